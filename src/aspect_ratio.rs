@@ -0,0 +1,80 @@
+use std::fmt::{Display, Formatter, Result};
+
+/// How the view box is fitted into the viewport when their aspect ratios differ, written as the
+/// `preserveAspectRatio` attribute.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AspectRatio {
+    /// Scales the view box non-uniformly so that it exactly fills the viewport.
+    Stretch,
+    /// Scales the view box uniformly so that it is entirely visible, leaving empty bands.
+    Meet(Align),
+    /// Scales the view box uniformly so that it covers the whole viewport, cutting off the
+    /// overflowing parts.
+    Slice(Align),
+}
+
+impl Default for AspectRatio {
+    fn default() -> Self {
+        Self::Meet(Align::XMidYMid)
+    }
+}
+
+/// Which side of the viewport the view box is aligned to, along each axis.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Align {
+    XMinYMin,
+    XMidYMin,
+    XMaxYMin,
+    XMinYMid,
+    #[default]
+    XMidYMid,
+    XMaxYMid,
+    XMinYMax,
+    XMidYMax,
+    XMaxYMax,
+}
+
+impl Display for Align {
+    fn fmt(&self, fmt: &mut Formatter) -> Result {
+        fmt.write_str(match self {
+            Align::XMinYMin => "xMinYMin",
+            Align::XMidYMin => "xMidYMin",
+            Align::XMaxYMin => "xMaxYMin",
+            Align::XMinYMid => "xMinYMid",
+            Align::XMidYMid => "xMidYMid",
+            Align::XMaxYMid => "xMaxYMid",
+            Align::XMinYMax => "xMinYMax",
+            Align::XMidYMax => "xMidYMax",
+            Align::XMaxYMax => "xMaxYMax",
+        })
+    }
+}
+
+impl Display for AspectRatio {
+    fn fmt(&self, fmt: &mut Formatter) -> Result {
+        match self {
+            AspectRatio::Stretch => write!(fmt, "none"),
+            AspectRatio::Meet(align) => write!(fmt, "{align} meet"),
+            AspectRatio::Slice(align) => write!(fmt, "{align} slice"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default() {
+        assert_eq!(AspectRatio::default().to_string(), "xMidYMid meet");
+    }
+
+    #[test]
+    fn test_variants() {
+        assert_eq!(AspectRatio::Stretch.to_string(), "none");
+        assert_eq!(
+            AspectRatio::Slice(Align::XMinYMax).to_string(),
+            "xMinYMax slice"
+        );
+    }
+}
@@ -0,0 +1,16 @@
+use geo_types::{Coord, CoordNum};
+use num_traits::NumCast;
+
+pub(crate) fn to_f64<T: CoordNum>(coord: Coord<T>) -> Coord<f64> {
+    Coord {
+        x: NumCast::from(coord.x).unwrap_or(0f64),
+        y: NumCast::from(coord.y).unwrap_or(0f64),
+    }
+}
+
+pub(crate) fn from_f64<T: CoordNum>(coord: Coord<f64>) -> Coord<T> {
+    Coord {
+        x: NumCast::from(coord.x).unwrap_or_else(T::zero),
+        y: NumCast::from(coord.y).unwrap_or_else(T::zero),
+    }
+}
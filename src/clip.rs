@@ -0,0 +1,279 @@
+use crate::cast::{from_f64, to_f64};
+use crate::{Style, ViewBox};
+use geo_types::{Coord, CoordNum};
+
+const X_MIN: u8 = 1;
+const X_MAX: u8 = 2;
+const Y_MIN: u8 = 4;
+const Y_MAX: u8 = 8;
+
+/// Where a geometry lies relative to a [`ClipRect`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Visibility {
+    Inside,
+    Partial,
+    Outside,
+}
+
+/// Rectangle that geometries are cut to before being written, so that nothing lying outside of the
+/// viewport ends up in the output.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct ClipRect {
+    min: Coord<f64>,
+    max: Coord<f64>,
+}
+
+impl ClipRect {
+    /// The viewport of `style` if any, grown by the stroke width so that the edges created by
+    /// clipping stay out of sight.
+    pub(crate) fn from_style(style: &Style) -> Option<Self> {
+        let viewport = style.viewport?;
+        let margin = style.stroke_width.unwrap_or(1.0) as f64;
+        Some(Self::new(&viewport, margin))
+    }
+
+    pub(crate) fn new(viewport: &ViewBox, margin: f64) -> Self {
+        Self {
+            min: Coord {
+                x: viewport.min_x() as f64 - margin,
+                y: viewport.min_y() as f64 - margin,
+            },
+            max: Coord {
+                x: viewport.max_x() as f64 + margin,
+                y: viewport.max_y() as f64 + margin,
+            },
+        }
+    }
+
+    pub(crate) fn visibility<T: CoordNum>(
+        &self,
+        coords: impl IntoIterator<Item = Coord<T>>,
+    ) -> Visibility {
+        let mut outside = u8::MAX;
+        let mut partial = false;
+        for coord in coords {
+            let code = self.outcode(to_f64(coord));
+            outside &= code;
+            partial |= code != 0;
+        }
+        match (outside, partial) {
+            (0, false) => Visibility::Inside,
+            (0, true) => Visibility::Partial,
+            _ => Visibility::Outside,
+        }
+    }
+
+    fn outcode(&self, coord: Coord<f64>) -> u8 {
+        let mut code = 0;
+        if coord.x < self.min.x {
+            code |= X_MIN;
+        } else if coord.x > self.max.x {
+            code |= X_MAX;
+        }
+        if coord.y < self.min.y {
+            code |= Y_MIN;
+        } else if coord.y > self.max.y {
+            code |= Y_MAX;
+        }
+        code
+    }
+
+    /// Cohen–Sutherland clipping of a single segment.
+    pub(crate) fn clip_segment<T: CoordNum>(
+        &self,
+        start: Coord<T>,
+        end: Coord<T>,
+    ) -> Option<(Coord<T>, Coord<T>)> {
+        let (mut start, mut end) = (to_f64(start), to_f64(end));
+        let (mut start_code, mut end_code) = (self.outcode(start), self.outcode(end));
+        loop {
+            if start_code | end_code == 0 {
+                return Some((from_f64(start), from_f64(end)));
+            }
+            if start_code & end_code != 0 {
+                return None;
+            }
+            let code = if start_code != 0 {
+                start_code
+            } else {
+                end_code
+            };
+            let (dx, dy) = (end.x - start.x, end.y - start.y);
+            let coord = if code & X_MIN != 0 {
+                Coord {
+                    x: self.min.x,
+                    y: start.y + dy * (self.min.x - start.x) / dx,
+                }
+            } else if code & X_MAX != 0 {
+                Coord {
+                    x: self.max.x,
+                    y: start.y + dy * (self.max.x - start.x) / dx,
+                }
+            } else if code & Y_MIN != 0 {
+                Coord {
+                    x: start.x + dx * (self.min.y - start.y) / dy,
+                    y: self.min.y,
+                }
+            } else {
+                Coord {
+                    x: start.x + dx * (self.max.y - start.y) / dy,
+                    y: self.max.y,
+                }
+            };
+            if code == start_code {
+                start = coord;
+                start_code = self.outcode(start);
+            } else {
+                end = coord;
+                end_code = self.outcode(end);
+            }
+        }
+    }
+
+    /// Intersection with the axis-aligned rectangle from `min` to `max`, if they overlap.
+    pub(crate) fn clip_rect<T: CoordNum>(
+        &self,
+        min: Coord<T>,
+        max: Coord<T>,
+    ) -> Option<(Coord<T>, Coord<T>)> {
+        let (min, max) = (to_f64(min), to_f64(max));
+        let min = Coord {
+            x: min.x.max(self.min.x),
+            y: min.y.max(self.min.y),
+        };
+        let max = Coord {
+            x: max.x.min(self.max.x),
+            y: max.y.min(self.max.y),
+        };
+        (min.x <= max.x && min.y <= max.y).then(|| (from_f64(min), from_f64(max)))
+    }
+
+    /// Clips an open line string, which may be split into several visible pieces.
+    pub(crate) fn clip_line_string<T: CoordNum>(&self, coords: &[Coord<T>]) -> Vec<Vec<Coord<T>>> {
+        let mut pieces: Vec<Vec<Coord<T>>> = vec![];
+        for segment in coords.windows(2) {
+            if let Some((start, end)) = self.clip_segment(segment[0], segment[1]) {
+                match pieces.last_mut() {
+                    Some(piece) if piece.last() == Some(&start) => piece.push(end),
+                    _ => pieces.push(vec![start, end]),
+                }
+            }
+        }
+        pieces
+    }
+
+    /// Sutherland–Hodgman clipping of a ring, given without its closing coordinate.
+    ///
+    /// The result is a single ring which may run along the clip rectangle where the original
+    /// ring was split, which is invisible as long as the rectangle is larger than the viewport.
+    pub(crate) fn clip_ring<T: CoordNum>(&self, ring: &[Coord<T>]) -> Vec<Coord<T>> {
+        let mut output: Vec<Coord<f64>> = ring.iter().copied().map(to_f64).collect();
+        let bounds = [
+            (true, self.min.x, true),
+            (true, self.max.x, false),
+            (false, self.min.y, true),
+            (false, self.max.y, false),
+        ];
+        for (is_x, bound, is_min) in bounds {
+            let input = std::mem::take(&mut output);
+            let Some(&last) = input.last() else {
+                break;
+            };
+            let value = |coord: Coord<f64>| if is_x { coord.x } else { coord.y };
+            let inside = |coord| {
+                if is_min {
+                    value(coord) >= bound
+                } else {
+                    value(coord) <= bound
+                }
+            };
+            let intersect = |a: Coord<f64>, b: Coord<f64>| {
+                let t = (bound - value(a)) / (value(b) - value(a));
+                Coord {
+                    x: a.x + t * (b.x - a.x),
+                    y: a.y + t * (b.y - a.y),
+                }
+            };
+            let mut previous = last;
+            for &current in &input {
+                match (inside(previous), inside(current)) {
+                    (true, true) => output.push(current),
+                    (true, false) => output.push(intersect(previous, current)),
+                    (false, true) => {
+                        output.push(intersect(previous, current));
+                        output.push(current);
+                    }
+                    (false, false) => {}
+                }
+                previous = current;
+            }
+        }
+        output.into_iter().map(from_f64).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rect() -> ClipRect {
+        ClipRect::new(&ViewBox::new(0.0, 0.0, 10.0, 10.0), 0.0)
+    }
+
+    #[test]
+    fn test_clip_segment() {
+        let clipped = rect().clip_segment(Coord { x: -5.0, y: 5.0 }, Coord { x: 5.0, y: 5.0 });
+        assert_eq!(
+            clipped,
+            Some((Coord { x: 0.0, y: 5.0 }, Coord { x: 5.0, y: 5.0 }))
+        );
+        let outside = rect().clip_segment(Coord { x: -5.0, y: -5.0 }, Coord { x: 20.0, y: -1.0 });
+        assert_eq!(outside, None);
+    }
+
+    #[test]
+    fn test_clip_line_string_in_pieces() {
+        let coords = [
+            Coord { x: 2.0, y: 5.0 },
+            Coord { x: 20.0, y: 5.0 },
+            Coord { x: 20.0, y: 8.0 },
+            Coord { x: 2.0, y: 8.0 },
+        ];
+        assert_eq!(
+            rect().clip_line_string(&coords),
+            vec![
+                vec![Coord { x: 2.0, y: 5.0 }, Coord { x: 10.0, y: 5.0 }],
+                vec![Coord { x: 10.0, y: 8.0 }, Coord { x: 2.0, y: 8.0 }],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_clip_ring() {
+        let ring = [
+            Coord { x: 5, y: 5 },
+            Coord { x: 15, y: 5 },
+            Coord { x: 15, y: 15 },
+            Coord { x: 5, y: 15 },
+        ];
+        assert_eq!(
+            rect().clip_ring(&ring),
+            vec![
+                Coord { x: 5, y: 10 },
+                Coord { x: 5, y: 5 },
+                Coord { x: 10, y: 5 },
+                Coord { x: 10, y: 10 },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_visibility() {
+        let inside = [Coord { x: 1.0, y: 1.0 }, Coord { x: 9.0, y: 9.0 }];
+        let partial = [Coord { x: 1.0, y: 1.0 }, Coord { x: 19.0, y: 9.0 }];
+        let outside = [Coord { x: 11.0, y: 1.0 }, Coord { x: 19.0, y: 9.0 }];
+        assert_eq!(rect().visibility(inside), Visibility::Inside);
+        assert_eq!(rect().visibility(partial), Visibility::Partial);
+        assert_eq!(rect().visibility(outside), Visibility::Outside);
+    }
+}
@@ -1,4 +1,7 @@
+use crate::named_colors;
+use std::error::Error;
 use std::fmt::{Display, Formatter, Result};
+use std::str::FromStr;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Color<'a> {
@@ -6,6 +9,10 @@ pub enum Color<'a> {
     Rgb(u8, u8, u8),
     Hex(u32),
     Hsl(u16, u8, u8),
+    /// Red, green and blue from 0 to 255, with an alpha from 0 to 1.
+    Rgba(u8, u8, u8, f32),
+    /// Hue in degrees, saturation and lightness in percent, with an alpha from 0 to 1.
+    Hsla(u16, u8, u8, f32),
 }
 
 impl Display for Color<'_> {
@@ -17,10 +24,310 @@ impl Display for Color<'_> {
             Color::Hsl(h, s, l) => {
                 write!(fmt, "hsl({},{}%,{}%)", h % 360, s.min(&100), l.min(&100))
             }
+            Color::Rgba(r, g, b, a) => write!(fmt, "rgba({r},{g},{b},{})", a.clamp(0.0, 1.0)),
+            Color::Hsla(h, s, l, a) => write!(
+                fmt,
+                "hsla({},{}%,{}%,{})",
+                h % 360,
+                s.min(&100),
+                l.min(&100),
+                a.clamp(0.0, 1.0)
+            ),
         }
     }
 }
 
+/// Error returned when a string is not a CSS color.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseColorError {
+    input: String,
+}
+
+impl Display for ParseColorError {
+    fn fmt(&self, fmt: &mut Formatter) -> Result {
+        write!(fmt, "invalid color `{}`", self.input)
+    }
+}
+
+impl Error for ParseColorError {}
+
+/// Parses the CSS syntax of colors: `#rgb`, `#rgba`, `#rrggbb`, `#rrggbbaa`, `rgb()`, `rgba()`,
+/// `hsl()`, `hsla()` and the named colors, ignoring case.
+///
+/// Example:
+/// ```
+/// use geo_svg::Color;
+///
+/// assert_eq!("#ff000080".parse(), Ok(Color::Rgba(255, 0, 0, 128.0 / 255.0)));
+/// assert_eq!("hsl(120deg 50% 25%)".parse(), Ok(Color::Hsl(120, 50, 25)));
+/// assert_eq!("SteelBlue".parse(), Ok(Color::Named("steelblue")));
+/// ```
+impl FromStr for Color<'static> {
+    type Err = ParseColorError;
+
+    fn from_str(input: &str) -> std::result::Result<Self, Self::Err> {
+        let error = || ParseColorError {
+            input: input.to_string(),
+        };
+        let color = input.trim().to_ascii_lowercase();
+        if let Some(hex) = color.strip_prefix('#') {
+            return parse_hex(hex).ok_or_else(error);
+        }
+        if let Some((function, arguments)) = color.split_once('(') {
+            let arguments = arguments.strip_suffix(')').ok_or_else(error)?;
+            return parse_function(function.trim(), arguments).ok_or_else(error);
+        }
+        if color == "transparent" {
+            return Ok(Color::Rgba(0, 0, 0, 0.0));
+        }
+        Color::named_checked(&color).map_err(|_| error())
+    }
+}
+
+fn parse_hex(hex: &str) -> Option<Color<'static>> {
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let digits: Vec<u8> = match hex.len() {
+        3 | 4 => hex
+            .chars()
+            .map(|c| u8::from_str_radix(&format!("{c}{c}"), 16))
+            .collect::<std::result::Result<_, _>>()
+            .ok()?,
+        6 | 8 => (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16))
+            .collect::<std::result::Result<_, _>>()
+            .ok()?,
+        _ => return None,
+    };
+    Some(match digits[..] {
+        [r, g, b] => Color::Hex(u32::from_be_bytes([0, r, g, b])),
+        [r, g, b, a] => Color::Rgba(r, g, b, f32::from(a) / 255.0),
+        _ => unreachable!(),
+    })
+}
+
+fn parse_function(function: &str, arguments: &str) -> Option<Color<'static>> {
+    let (arguments, alpha) = match arguments.split_once('/') {
+        Some((arguments, alpha)) => (arguments, Some(alpha.trim())),
+        None => (arguments, None),
+    };
+    let mut arguments: Vec<&str> = arguments
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|argument| !argument.is_empty())
+        .collect();
+    let alpha = match (alpha, arguments.len()) {
+        (Some(alpha), 3) => Some(alpha),
+        (None, 4) => arguments.pop(),
+        (None, 3) => None,
+        _ => return None,
+    };
+    let alpha = match alpha {
+        Some(alpha) => Some(parse_alpha(alpha)?),
+        None => None,
+    };
+    match function {
+        "rgb" | "rgba" => {
+            let r = parse_channel(arguments[0])?;
+            let g = parse_channel(arguments[1])?;
+            let b = parse_channel(arguments[2])?;
+            Some(match alpha {
+                Some(a) => Color::Rgba(r, g, b, a),
+                None => Color::Rgb(r, g, b),
+            })
+        }
+        "hsl" | "hsla" => {
+            let hue: f32 = arguments[0]
+                .strip_suffix("deg")
+                .unwrap_or(arguments[0])
+                .parse()
+                .ok()?;
+            let h = hue.rem_euclid(360.0).round() as u16 % 360;
+            let s = parse_percentage(arguments[1])?;
+            let l = parse_percentage(arguments[2])?;
+            Some(match alpha {
+                Some(a) => Color::Hsla(h, s, l, a),
+                None => Color::Hsl(h, s, l),
+            })
+        }
+        _ => None,
+    }
+}
+
+/// A channel from 0 to 255, or a percentage of 255.
+fn parse_channel(channel: &str) -> Option<u8> {
+    let value = match channel.strip_suffix('%') {
+        Some(percentage) => percentage.parse::<f32>().ok()? * 2.55,
+        None => channel.parse::<f32>().ok()?,
+    };
+    value
+        .is_finite()
+        .then(|| value.round().clamp(0.0, 255.0) as u8)
+}
+
+fn parse_percentage(percentage: &str) -> Option<u8> {
+    let value = percentage
+        .strip_suffix('%')
+        .unwrap_or(percentage)
+        .parse::<f32>()
+        .ok()?;
+    value
+        .is_finite()
+        .then(|| value.round().clamp(0.0, 100.0) as u8)
+}
+
+/// An alpha from 0 to 1, or a percentage.
+fn parse_alpha(alpha: &str) -> Option<f32> {
+    let value = match alpha.strip_suffix('%') {
+        Some(percentage) => percentage.parse::<f32>().ok()? / 100.0,
+        None => alpha.parse::<f32>().ok()?,
+    };
+    value.is_finite().then(|| value.clamp(0.0, 1.0))
+}
+
+impl Color<'static> {
+    /// The named color `name`, ignoring case, or an error if it is not one of the named colors of
+    /// CSS, which [`Color::Named`] would silently render as black.
+    ///
+    /// Example:
+    /// ```
+    /// use geo_svg::Color;
+    ///
+    /// assert_eq!(Color::named_checked("LightGrey"), Ok(Color::LIGHT_GREY));
+    /// assert!(Color::named_checked("lightgrey2").is_err());
+    /// ```
+    pub fn named_checked(name: &str) -> std::result::Result<Self, ParseColorError> {
+        named_colors::find(name)
+            .map(|(name, _)| Color::Named(name))
+            .ok_or_else(|| ParseColorError {
+                input: name.to_string(),
+            })
+    }
+
+    /// All the named colors of CSS, in alphabetical order.
+    pub fn all_named() -> impl Iterator<Item = Color<'static>> {
+        named_colors::NAMED_COLORS
+            .iter()
+            .map(|(name, _)| Color::Named(name))
+    }
+}
+
+impl<'a> Color<'a> {
+    /// Red, green and blue from 0 to 255, ignoring the alpha, or `None` for an unknown named
+    /// color.
+    pub fn to_rgb(&self) -> Option<[u8; 3]> {
+        let [r, g, b, _] = self.to_rgba()?;
+        Some([r, g, b].map(|channel| (channel * 255.0).round() as u8))
+    }
+
+    /// Red, green, blue and alpha from 0 to 1, or `None` for an unknown named color.
+    pub fn to_rgba(&self) -> Option<[f32; 4]> {
+        let channel = |channel: u8| f32::from(channel) / 255.0;
+        let hex = |hex: u32| {
+            let [_, r, g, b] = hex.to_be_bytes();
+            [channel(r), channel(g), channel(b), 1.0]
+        };
+        Some(match *self {
+            Color::Named(name) => hex(named_colors::find(name)?.1),
+            Color::Rgb(r, g, b) => [channel(r), channel(g), channel(b), 1.0],
+            Color::Hex(value) => hex(value),
+            Color::Hsl(h, s, l) => hsl_to_rgba(h, s, l, 1.0),
+            Color::Rgba(r, g, b, a) => [channel(r), channel(g), channel(b), a.clamp(0.0, 1.0)],
+            Color::Hsla(h, s, l, a) => hsl_to_rgba(h, s, l, a.clamp(0.0, 1.0)),
+        })
+    }
+
+    /// Color of red, green, blue and alpha from 0 to 1, opaque colors being [`Color::Rgb`].
+    pub fn from_rgba([r, g, b, a]: [f32; 4]) -> Color<'static> {
+        let channel = |channel: f32| (channel.clamp(0.0, 1.0) * 255.0).round() as u8;
+        let a = a.clamp(0.0, 1.0);
+        if a == 1.0 {
+            Color::Rgb(channel(r), channel(g), channel(b))
+        } else {
+            Color::Rgba(channel(r), channel(g), channel(b), a)
+        }
+    }
+
+    /// Increases the lightness by `amount`, from 0 to 1, keeping hue and saturation.
+    ///
+    /// Unknown named colors are returned as is.
+    pub fn lighten(&self, amount: f32) -> Color<'a> {
+        let Some([r, g, b, a]) = self.to_rgba() else {
+            return *self;
+        };
+        let (h, s, l) = rgb_to_hsl(r, g, b);
+        let [r, g, b] = hsl_to_rgb(h, s, (l + amount).clamp(0.0, 1.0));
+        Color::from_rgba([r, g, b, a])
+    }
+
+    /// Decreases the lightness by `amount`, from 0 to 1, keeping hue and saturation.
+    ///
+    /// Unknown named colors are returned as is.
+    pub fn darken(&self, amount: f32) -> Color<'a> {
+        self.lighten(-amount)
+    }
+
+    /// Blends with `other`, `weight` being the proportion of `other`, from 0 to 1.
+    ///
+    /// Unknown named colors are returned as is.
+    pub fn mix(&self, other: &Color, weight: f32) -> Color<'a> {
+        let (Some(from), Some(to)) = (self.to_rgba(), other.to_rgba()) else {
+            return *self;
+        };
+        let weight = weight.clamp(0.0, 1.0);
+        Color::from_rgba(std::array::from_fn(|i| {
+            from[i] + (to[i] - from[i]) * weight
+        }))
+    }
+}
+
+fn hsl_to_rgba(h: u16, s: u8, l: u8, a: f32) -> [f32; 4] {
+    let [r, g, b] = hsl_to_rgb(
+        f32::from(h % 360),
+        f32::from(s.min(100)) / 100.0,
+        f32::from(l.min(100)) / 100.0,
+    );
+    [r, g, b, a]
+}
+
+/// Red, green and blue from 0 to 1 of a hue in degrees and a saturation and lightness from 0 to
+/// 1.
+fn hsl_to_rgb(h: f32, s: f32, l: f32) -> [f32; 3] {
+    let chroma = (1.0 - (2.0 * l - 1.0).abs()) * s;
+    let x = chroma * (1.0 - ((h / 60.0) % 2.0 - 1.0).abs());
+    let m = l - chroma / 2.0;
+    let (r, g, b) = match (h / 60.0) as u8 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    [r + m, g + m, b + m]
+}
+
+/// Hue in degrees, saturation and lightness from 0 to 1 of red, green and blue from 0 to 1.
+fn rgb_to_hsl(r: f32, g: f32, b: f32) -> (f32, f32, f32) {
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let l = (max + min) / 2.0;
+    let chroma = max - min;
+    if chroma == 0.0 {
+        return (0.0, 0.0, l);
+    }
+    let s = chroma / (1.0 - (2.0 * l - 1.0).abs());
+    let h = if max == r {
+        ((g - b) / chroma).rem_euclid(6.0)
+    } else if max == g {
+        (b - r) / chroma + 2.0
+    } else {
+        (r - g) / chroma + 4.0
+    };
+    (h * 60.0, s, l)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -45,4 +352,96 @@ mod tests {
     fn test_hsl() {
         assert_eq!(format!("{}", Color::Hsl(0, 100, 50)), "hsl(0,100%,50%)");
     }
+
+    #[test]
+    fn test_alpha() {
+        assert_eq!(
+            format!("{}", Color::Rgba(255, 0, 0, 0.5)),
+            "rgba(255,0,0,0.5)"
+        );
+        assert_eq!(
+            format!("{}", Color::Hsla(120, 50, 25, 0.25)),
+            "hsla(120,50%,25%,0.25)"
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("#f00".parse(), Ok(Color::Hex(0xFF0000)));
+        assert_eq!("#F0F8FF".parse(), Ok(Color::Hex(0xF0F8FF)));
+        assert_eq!("#f008".parse(), Ok(Color::Rgba(255, 0, 0, 136.0 / 255.0)));
+        assert_eq!("rgb(255, 0, 10)".parse(), Ok(Color::Rgb(255, 0, 10)));
+        assert_eq!("rgba(255,0,0,0.5)".parse(), Ok(Color::Rgba(255, 0, 0, 0.5)));
+        assert_eq!(
+            "rgb(100% 0% 0% / 50%)".parse(),
+            Ok(Color::Rgba(255, 0, 0, 0.5))
+        );
+        assert_eq!(
+            "hsla(-90, 100%, 50%, 1)".parse(),
+            Ok(Color::Hsla(270, 100, 50, 1.0))
+        );
+        assert_eq!(" Red ".parse(), Ok(Color::Named("red")));
+        assert_eq!("transparent".parse(), Ok(Color::Rgba(0, 0, 0, 0.0)));
+        for invalid in [
+            "",
+            "#12",
+            "#ggg",
+            "rgb(1, 2)",
+            "rgb(1, 2, 3",
+            "cmyk(0, 0, 0)",
+            "reddish",
+        ] {
+            assert_eq!(
+                invalid.parse::<Color>().unwrap_err().to_string(),
+                format!("invalid color `{invalid}`")
+            );
+        }
+    }
+
+    #[test]
+    fn test_named_checked() {
+        assert_eq!(Color::named_checked("Red"), Ok(Color::RED));
+        assert_eq!(
+            Color::named_checked("lightgrey2").unwrap_err().to_string(),
+            "invalid color `lightgrey2`"
+        );
+        assert_eq!(Color::all_named().count(), 148);
+        assert!(Color::all_named().all(|color| color.to_rgb().is_some()));
+    }
+
+    #[test]
+    fn test_to_rgba() {
+        assert_eq!(Color::Named("Blue").to_rgba(), Some([0.0, 0.0, 1.0, 1.0]));
+        assert_eq!(Color::Named("reddish").to_rgba(), None);
+        assert_eq!(
+            Color::Hsl(120, 100, 50).to_rgba(),
+            Some([0.0, 1.0, 0.0, 1.0])
+        );
+        assert_eq!(
+            Color::Rgba(0, 0, 0, 0.5).to_rgba(),
+            Some([0.0, 0.0, 0.0, 0.5])
+        );
+        assert_eq!(
+            Color::from_rgba([1.0, 0.0, 0.0, 1.0]),
+            Color::Rgb(255, 0, 0)
+        );
+    }
+
+    #[test]
+    fn test_lighten_darken_mix() {
+        assert_eq!(
+            Color::Hex(0xFF0000).lighten(0.25),
+            Color::Rgb(255, 128, 128)
+        );
+        assert_eq!(Color::Named("red").darken(0.25), Color::Rgb(128, 0, 0));
+        assert_eq!(Color::Named("white").darken(2.0), Color::Rgb(0, 0, 0));
+        assert_eq!(
+            Color::Named("black").mix(&Color::Rgba(255, 255, 255, 0.0), 0.5),
+            Color::Rgba(128, 128, 128, 0.5)
+        );
+        assert_eq!(
+            Color::Named("reddish").lighten(0.5),
+            Color::Named("reddish")
+        );
+    }
 }
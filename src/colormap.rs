@@ -0,0 +1,161 @@
+use crate::Color;
+
+/// Continuous color scales, to map values to colors.
+///
+/// Example:
+/// ```
+/// use geo_types::Point;
+/// use geo_svg::{Colormap, CombineToSVG, Normalization};
+///
+/// let points = vec![Point::new(0.0, 0.0), Point::new(1.0, 1.0)];
+/// let elevations = [120.0, 2450.0];
+/// let svg = points.combine_to_svg().unwrap().with_colormap(
+///     Colormap::Viridis,
+///     Normalization::Linear,
+///     |index| elevations[index],
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Colormap {
+    /// Perceptually uniform scale from dark blue to yellow.
+    #[default]
+    Viridis,
+    /// Perceptually uniform scale from black to light yellow through purple.
+    Magma,
+    /// Perceptually uniform scale from dark blue to yellow through magenta.
+    Plasma,
+    /// Perceptually uniform scale from dark blue to yellow, readable with color vision
+    /// deficiencies.
+    Cividis,
+    /// Diverging ColorBrewer scale from dark red to dark blue through white, for values around
+    /// a meaningful midpoint.
+    RdBu,
+}
+
+impl Colormap {
+    /// Colors at evenly spaced positions of the scale, as `0xRRGGBB`.
+    fn stops(&self) -> &'static [u32] {
+        match self {
+            Colormap::Viridis => &[
+                0x440154, 0x482475, 0x414487, 0x355F8D, 0x2A788E, 0x21918C, 0x22A884, 0x44BF70,
+                0x7AD151, 0xBDDF26, 0xFDE725,
+            ],
+            Colormap::Magma => &[
+                0x000004, 0x140E36, 0x3B0F70, 0x641A80, 0x8C2981, 0xB73779, 0xDE4968, 0xF7705C,
+                0xFE9F6D, 0xFECF92, 0xFCFDBF,
+            ],
+            Colormap::Plasma => &[
+                0x0D0887, 0x41049D, 0x6A00A8, 0x8F0DA4, 0xB12A90, 0xCC4778, 0xE16462, 0xF2844B,
+                0xFCA636, 0xFCCE25, 0xF0F921,
+            ],
+            Colormap::Cividis => &[
+                0x00204D, 0x00336F, 0x39486B, 0x575C6D, 0x707173, 0x8A8779, 0xA69D75, 0xC4B56C,
+                0xE4CF5B, 0xFFEA46,
+            ],
+            Colormap::RdBu => &[
+                0x67001F, 0xB2182B, 0xD6604D, 0xF4A582, 0xFDDBC7, 0xF7F7F7, 0xD1E5F0, 0x92C5DE,
+                0x4393C3, 0x2166AC, 0x053061,
+            ],
+        }
+    }
+
+    /// Color at `position` along the scale, from 0 to 1.
+    pub fn color(&self, position: f64) -> Color<'static> {
+        let stops = self.stops();
+        let position = if position.is_nan() {
+            0.0
+        } else {
+            position.clamp(0.0, 1.0) * (stops.len() - 1) as f64
+        };
+        let index = (position.floor() as usize).min(stops.len() - 2);
+        let t = position - index as f64;
+        let channel = |stop: u32, shift: u32| f64::from((stop >> shift) & 0xFF);
+        let mix = |shift| {
+            let (from, to) = (
+                channel(stops[index], shift),
+                channel(stops[index + 1], shift),
+            );
+            (from + (to - from) * t).round() as u8
+        };
+        Color::Rgb(mix(16), mix(8), mix(0))
+    }
+}
+
+/// How values are mapped to positions along a [`Colormap`], relative to the other values.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Normalization {
+    /// Proportionally between the smallest and the largest value.
+    #[default]
+    Linear,
+    /// Proportionally to the logarithm of the values, for values spanning several orders of
+    /// magnitude. Values which are not strictly positive are left out.
+    Log,
+    /// By rank, so that each color is used for as many values as the others, which suits skewed
+    /// distributions.
+    Quantile,
+}
+
+impl Normalization {
+    /// Positions of `values` between 0 and 1, `None` for the values which cannot be mapped such
+    /// as NaN.
+    pub fn normalize(&self, values: &[f64]) -> Vec<Option<f64>> {
+        let values: Vec<Option<f64>> = values
+            .iter()
+            .map(|&value| match self {
+                Normalization::Log => (value > 0.0).then(|| value.ln()),
+                Normalization::Linear | Normalization::Quantile => Some(value),
+            })
+            .map(|value| value.filter(|value| value.is_finite()))
+            .collect();
+        let mut sorted: Vec<f64> = values.iter().flatten().copied().collect();
+        sorted.sort_by(f64::total_cmp);
+        let (Some(&min), Some(&max)) = (sorted.first(), sorted.last()) else {
+            return values;
+        };
+        values
+            .into_iter()
+            .map(|value| {
+                value.map(|value| match self {
+                    _ if min == max => 0.5,
+                    Normalization::Linear | Normalization::Log => (value - min) / (max - min),
+                    Normalization::Quantile => {
+                        let below = sorted.partition_point(|other| *other < value);
+                        let equal = sorted.partition_point(|other| *other <= value) - below;
+                        (below as f64 + (equal - 1) as f64 / 2.0) / (sorted.len() - 1) as f64
+                    }
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_color() {
+        assert_eq!(Colormap::Viridis.color(0.0), Color::Rgb(0x44, 0x01, 0x54));
+        assert_eq!(Colormap::Viridis.color(1.0), Color::Rgb(0xFD, 0xE7, 0x25));
+        assert_eq!(Colormap::RdBu.color(0.5), Color::Rgb(0xF7, 0xF7, 0xF7));
+        assert_eq!(Colormap::Magma.color(0.05), Color::Rgb(10, 7, 29));
+        assert_eq!(Colormap::Viridis.color(2.0), Colormap::Viridis.color(1.0));
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(
+            Normalization::Linear.normalize(&[1.0, 3.0, f64::NAN, 2.0]),
+            vec![Some(0.0), Some(1.0), None, Some(0.5)]
+        );
+        assert_eq!(
+            Normalization::Log.normalize(&[1.0, 100.0, 0.0, 10.0]),
+            vec![Some(0.0), Some(1.0), None, Some(0.5)]
+        );
+        assert_eq!(
+            Normalization::Quantile.normalize(&[1.0, 1000.0, 2.0, 3.0, 3.0]),
+            vec![Some(0.0), Some(1.0), Some(0.25), Some(0.625), Some(0.625)]
+        );
+        assert_eq!(Normalization::Linear.normalize(&[4.0]), vec![Some(0.5)]);
+    }
+}
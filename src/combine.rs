@@ -1,9 +1,15 @@
-use crate::{Svg, ToSvg};
+use crate::{Palette, Svg, ToSvg};
 
 /// This trait let's you combine multiple things that can be converted to a SVG into one big
 /// compound SVG
 pub trait CombineToSVG {
     fn combine_to_svg(&self) -> Option<Svg<'_>>;
+
+    /// Same as [`CombineToSVG::combine_to_svg`] with each element in its own fill and stroke
+    /// color from `palette`.
+    fn combine_to_svg_with_palette(&self, palette: Palette) -> Option<Svg<'_>> {
+        self.combine_to_svg().map(|svg| svg.with_palette(palette))
+    }
 }
 
 impl<S: ToSvg> CombineToSVG for &[S] {
@@ -17,3 +23,24 @@ impl<S: ToSvg> CombineToSVG for Vec<S> {
         self.iter().map(|s| s.to_svg()).reduce(|a, b| a.and(b))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use geo_types::Point;
+
+    #[test]
+    fn test_combine_to_svg_with_palette() {
+        let points = vec![Point::new(0.0, 0.0), Point::new(1.0, 1.0)];
+        let svg = points
+            .combine_to_svg_with_palette(Palette::OkabeIto)
+            .unwrap()
+            .to_string();
+        assert!(
+            svg.contains(r##"<circle cx="0.0" cy="0.0" r="1" fill="#E69F00" stroke="#E69F00"/>"##)
+        );
+        assert!(
+            svg.contains(r##"<circle cx="1.0" cy="1.0" r="1" fill="#56B4E9" stroke="#56B4E9"/>"##)
+        );
+    }
+}
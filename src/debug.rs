@@ -0,0 +1,179 @@
+use crate::{Palette, PrettyPrint, Svg, ToSvg};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Environment variable setting the directory written to by [`dbg_svg!`](crate::dbg_svg),
+/// the temporary directory by default.
+pub const DBG_DIR_VAR: &str = "GEO_SVG_DBG_DIR";
+
+/// Tuples of values convertible to SVG, the arguments of [`dbg_svg!`](crate::dbg_svg).
+pub trait ToSvgs {
+    fn to_svgs(&self) -> Vec<Svg<'_>>;
+}
+
+macro_rules! impl_to_svgs {
+    ($($name:ident $index:tt),+) => {
+        impl<$($name: ToSvg),+> ToSvgs for ($($name,)+) {
+            fn to_svgs(&self) -> Vec<Svg<'_>> {
+                vec![$(self.$index.to_svg()),+]
+            }
+        }
+    };
+}
+
+impl_to_svgs!(A 0);
+impl_to_svgs!(A 0, B 1);
+impl_to_svgs!(A 0, B 1, C 2);
+impl_to_svgs!(A 0, B 1, C 2, D 3);
+impl_to_svgs!(A 0, B 1, C 2, D 3, E 4);
+impl_to_svgs!(A 0, B 1, C 2, D 3, E 4, F 5);
+impl_to_svgs!(A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+impl_to_svgs!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+impl_to_svgs!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8);
+impl_to_svgs!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9);
+impl_to_svgs!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10);
+impl_to_svgs!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11);
+
+/// Combines `values` into a single SVG, each of them in its own color.
+pub fn debug_svg(values: &impl ToSvgs) -> Option<Svg<'_>> {
+    values.to_svgs().into_iter().reduce(Svg::and).map(|svg| {
+        svg.with_palette(Palette::Tableau10)
+            .with_fill_opacity(0.4)
+            .with_pretty_print(PrettyPrint::default())
+    })
+}
+
+/// Writes the SVG of `values` to a new file in the directory given by [`DBG_DIR_VAR`] and
+/// returns its path, named after the `file` and `line` where it is called from.
+pub fn write_debug_svg(values: &impl ToSvgs, file: &str, line: u32) -> std::io::Result<PathBuf> {
+    let directory = std::env::var_os(DBG_DIR_VAR)
+        .map(PathBuf::from)
+        .unwrap_or_else(std::env::temp_dir);
+    write_debug_svg_in(&directory, values, file, line)
+}
+
+fn write_debug_svg_in(
+    directory: &Path,
+    values: &impl ToSvgs,
+    file: &str,
+    line: u32,
+) -> std::io::Result<PathBuf> {
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+    std::fs::create_dir_all(directory)?;
+    let stem: String = file
+        .trim_end_matches(".rs")
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_millis());
+    let path = directory.join(format!(
+        "geo-svg-{stem}-{line}-{timestamp}-{}-{}.svg",
+        std::process::id(),
+        COUNTER.fetch_add(1, Ordering::Relaxed),
+    ));
+    let content = debug_svg(values).map_or_else(String::new, |svg| svg.to_string());
+    std::fs::write(&path, content)?;
+    Ok(path)
+}
+
+/// Like [`dbg!`], but writes its arguments to an SVG file, each in its own color, and prints
+/// the path of the file along with the source location to stderr.
+///
+/// The directory of the file can be set with the `GEO_SVG_DBG_DIR` environment variable, it
+/// is the temporary directory by default. The arguments are returned, as a tuple if there are
+/// several of them.
+///
+/// Example:
+/// ```no_run
+/// use geo_types::{Point, Rect};
+/// use geo_svg::dbg_svg;
+///
+/// let rect = Rect::new((0.0, 0.0), (10.0, 10.0));
+/// let (rect, point) = dbg_svg!(rect, Point::new(5.0, 5.0));
+/// ```
+#[macro_export]
+macro_rules! dbg_svg {
+    (@write $values:ident, $($value:expr),+) => {
+        match $crate::__private::write_debug_svg(&$values, file!(), line!()) {
+            Ok(path) => ::std::eprintln!(
+                "[{}:{}:{}] {} = {}",
+                file!(),
+                line!(),
+                column!(),
+                stringify!($($value),+),
+                path.display(),
+            ),
+            Err(error) => ::std::eprintln!(
+                "[{}:{}:{}] cannot write the SVG of {}: {}",
+                file!(),
+                line!(),
+                column!(),
+                stringify!($($value),+),
+                error,
+            ),
+        }
+    };
+    ($value:expr $(,)?) => {
+        match ($value,) {
+            values => {
+                $crate::dbg_svg!(@write values, $value);
+                values.0
+            }
+        }
+    };
+    ($($value:expr),+ $(,)?) => {
+        match ($($value,)+) {
+            values => {
+                $crate::dbg_svg!(@write values, $($value),+);
+                values
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use geo_types::{LineString, Point};
+
+    #[test]
+    fn test_distinct_colors() {
+        let line = LineString::from(vec![(0.0, 0.0), (1.0, 1.0)]);
+        let svg = debug_svg(&(Point::new(0.0, 0.0), line))
+            .unwrap()
+            .to_string();
+        assert!(svg.contains(r##"<circle cx="0.0" cy="0.0" r="1" fill="#4E79A7""##));
+        assert!(svg.contains(r##"fill="#F28E2B""##));
+    }
+
+    #[test]
+    fn test_dbg_svg() {
+        let point = Point::new(1.0, 2.0);
+        assert_eq!(crate::dbg_svg!(point), point);
+        let line = LineString::from(vec![(0.0, 0.0), (1.0, 1.0)]);
+        let (point, line) = crate::dbg_svg!(point, line.clone());
+        assert_eq!(point, Point::new(1.0, 2.0));
+        assert_eq!(line.0.len(), 2);
+        // the macros write to the default directory, unless set otherwise
+        let directory = std::env::var_os(DBG_DIR_VAR)
+            .map(PathBuf::from)
+            .unwrap_or_else(std::env::temp_dir);
+        let written = format!("-{}-", std::process::id());
+        for entry in std::fs::read_dir(directory).unwrap() {
+            let path = entry.unwrap().path();
+            let name = path.file_name().unwrap().to_string_lossy();
+            if name.starts_with("geo-svg-src_debug-") && name.contains(&written) {
+                std::fs::remove_file(path).unwrap();
+            }
+        }
+
+        let directory = std::env::temp_dir().join(format!("geo-svg-dbg-{}", std::process::id()));
+        let path = write_debug_svg_in(&directory, &(point,), file!(), line!()).unwrap();
+        assert_eq!(path.parent(), Some(directory.as_path()));
+        assert!(std::fs::read_to_string(&path).unwrap().contains("<circle"));
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+}
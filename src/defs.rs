@@ -0,0 +1,26 @@
+use std::fmt::Debug;
+
+/// Derives a stable element id from the definition of a reusable element such as a pattern.
+///
+/// Identical definitions get identical ids, so they can be referenced from any number of
+/// elements and still be emitted only once in `<defs>`.
+pub(crate) fn id(prefix: &str, definition: &impl Debug) -> String {
+    // FNV-1a, which unlike `DefaultHasher` is guaranteed to be stable across Rust releases
+    let hash = format!("{definition:?}")
+        .bytes()
+        .fold(0xcbf29ce484222325u64, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+        });
+    format!("{prefix}-{hash:016x}")
+}
+
+/// Removes duplicated definitions while preserving the order of first appearance.
+pub(crate) fn dedup(defs: Vec<String>) -> Vec<String> {
+    let mut unique: Vec<String> = Vec::with_capacity(defs.len());
+    for def in defs {
+        if !unique.contains(&def) {
+            unique.push(def);
+        }
+    }
+    unique
+}
@@ -0,0 +1,6 @@
+/// Escapes the characters of `text` which cannot appear as is in the content of an element.
+pub(crate) fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
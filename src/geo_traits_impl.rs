@@ -0,0 +1,95 @@
+//! Rendering of any geometry implementing the [`geo_traits`] geometry traits.
+
+use crate::svg_impl::{geometry_non_finite_coords, geometry_svg_str, geometry_viewbox};
+use crate::{Style, ToSvgStr, ViewBox};
+use geo_traits::GeometryTrait;
+use geo_types::CoordNum;
+
+/// Renders a geometry implementing [`GeometryTrait`], e.g. from `wkb`, `geozero` or a zero-copy
+/// type of your own, without converting it to `geo_types` first.
+///
+/// Example:
+/// ```
+/// use geo_svg::{AnyGeometry, ToSvg};
+/// use geo_types::{LineString, MultiLineString};
+///
+/// let lines = MultiLineString::new(vec![LineString::from(vec![(0.0, 0.0), (10.0, 5.0)])]);
+/// let geometry = AnyGeometry(&lines);
+/// assert_eq!(geometry.to_svg().svg_str(), lines.to_svg().svg_str());
+/// ```
+pub struct AnyGeometry<G>(pub G);
+
+impl<G> ToSvgStr for AnyGeometry<G>
+where
+    G: GeometryTrait<T: CoordNum>,
+{
+    fn to_svg_str(&self, style: &Style) -> String {
+        geometry_svg_str(&self.0, style)
+    }
+
+    fn viewbox(&self, style: &Style) -> ViewBox {
+        geometry_viewbox(&self.0, style)
+    }
+
+    fn non_finite_coords(&self, style: &Style) -> usize {
+        geometry_non_finite_coords(&self.0, style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ToSvg;
+    use crate::svg_impl::polygon_svg_str;
+    use geo_types::{
+        Geometry, GeometryCollection, Line, LineString, MultiPoint, MultiPolygon, Point, Polygon,
+        Rect, Triangle, polygon,
+    };
+
+    #[test]
+    fn test_same_as_geo_types() {
+        let square = Rect::new((0.0, 0.0), (4.0, 4.0));
+        let holed = Polygon::new(
+            square.to_polygon().exterior().clone(),
+            vec![LineString::from(vec![(1.0, 1.0), (2.0, 1.0), (1.0, 2.0)])],
+        );
+        let collection = GeometryCollection(vec![
+            Geometry::Point(Point::new(1.0, 2.0)),
+            Geometry::Line(Line::new((0.0, 0.0), (1.0, 1.0))),
+            Geometry::LineString(LineString::from(vec![(0.0, 0.0), (5.0, 1.0), (6.0, 3.0)])),
+            Geometry::Polygon(holed.clone()),
+            Geometry::MultiPoint(MultiPoint::from(vec![(3.0, 3.0)])),
+            Geometry::MultiPolygon(MultiPolygon::new(vec![holed])),
+            Geometry::Rect(square),
+            Geometry::Triangle(Triangle::new(
+                (0.0, 0.0).into(),
+                (1.0, 0.0).into(),
+                (0.0, 1.0).into(),
+            )),
+        ]);
+        let geometry = AnyGeometry(&collection);
+        let any = geometry.to_svg().with_radius(2.0);
+        let concrete = collection.to_svg().with_radius(2.0);
+        assert_eq!(any.svg_str(), concrete.svg_str());
+        assert_eq!(any.viewbox(), concrete.viewbox());
+    }
+
+    #[test]
+    fn test_unclosed_rings() {
+        // geo_types closes the rings of its polygons, other implementors may not
+        let closed = polygon![(x: 0.0, y: 0.0), (x: 2.0, y: 0.0), (x: 0.0, y: 2.0)];
+        let unclosed = vec![closed.exterior().0[..3].to_vec()];
+        assert_eq!(
+            polygon_svg_str(unclosed, &Style::default()),
+            closed.to_svg_str(&Style::default())
+        );
+    }
+
+    #[cfg(feature = "wkt")]
+    #[test]
+    fn test_wkt() {
+        let wkt: wkt::Wkt<f64> = "MULTILINESTRING((0 0, 10 5), (1 1, 2 2))".parse().unwrap();
+        let geometry = AnyGeometry(&wkt);
+        assert_eq!(geometry.to_svg().svg_str(), wkt.to_svg().svg_str());
+    }
+}
@@ -0,0 +1,292 @@
+//! Rendering of GeoJSON features, styled after their
+//! [simplestyle](https://github.com/mapbox/simplestyle-spec) properties.
+
+use crate::escape::escape;
+use crate::{Color, Colormap, Normalization, Style, StyleEach, StyledItems, ToSvgStr, ViewBox};
+use geo_types::Geometry;
+use geojson::{Feature, FeatureCollection};
+
+/// Geometry of the feature, if it has one that converts to `geo_types`, along with its style
+/// inheriting from `parent`.
+///
+/// The geometry is converted again by each rendering method, such as `to_svg_str` and `viewbox`,
+/// as features are rendered from a borrow and nothing is cached between calls.
+fn styled_geometry<'p>(
+    feature: &Feature,
+    parent: &Style<'p>,
+) -> Option<(Geometry<f64>, Style<'p>)> {
+    let geometry = Geometry::try_from(&feature.geometry.as_ref()?.value).ok()?;
+    let style = feature_style(feature, &geometry, parent);
+    Some((geometry, style))
+}
+
+/// Style set by the simplestyle properties of the feature, the fields left unset being inherited
+/// from `parent`.
+///
+/// `marker-color` takes precedence over `fill` for points, and properties whose value is not a
+/// valid color or number are ignored.
+fn feature_style<'p>(feature: &Feature, geometry: &Geometry<f64>, parent: &Style<'p>) -> Style<'p> {
+    let color = |key| {
+        feature
+            .property(key)?
+            .as_str()?
+            .parse::<Color<'static>>()
+            .ok()
+    };
+    let number = |key| feature.property(key)?.as_f64().map(|value| value as f32);
+    let fill = match geometry {
+        Geometry::Point(_) | Geometry::MultiPoint(_) => color("marker-color").or(color("fill")),
+        _ => color("fill"),
+    };
+    Style {
+        fill: fill.map(Into::into),
+        fill_opacity: number("fill-opacity"),
+        stroke_color: color("stroke"),
+        stroke_width: number("stroke-width"),
+        stroke_opacity: number("stroke-opacity"),
+        ..Style::default()
+    }
+    .inherit(parent)
+}
+
+/// Value of the `title` property, shown as a tooltip by most viewers.
+fn title(feature: &Feature) -> Option<String> {
+    match feature.property("title")? {
+        serde_json::Value::String(title) => Some(title.clone()),
+        serde_json::Value::Null => None,
+        title => Some(title.to_string()),
+    }
+}
+
+/// Renders the feature, grouped with a `<title>` if `title` is given.
+fn feature_svg_str(feature: &Feature, style: &Style, title: Option<String>) -> String {
+    let Some((geometry, style)) = styled_geometry(feature, style) else {
+        return String::new();
+    };
+    let svg = geometry.to_svg_str(&style);
+    match title {
+        Some(title) if !svg.is_empty() => {
+            format!("<g><title>{}</title>{svg}</g>", escape(&title))
+        }
+        _ => svg,
+    }
+}
+
+fn feature_viewbox(feature: &Feature, style: &Style) -> ViewBox {
+    styled_geometry(feature, style).map_or_else(ViewBox::default, |(geometry, style)| {
+        geometry.viewbox(&style)
+    })
+}
+
+fn feature_non_finite_coords(feature: &Feature, style: &Style) -> usize {
+    styled_geometry(feature, style)
+        .map_or(0, |(geometry, style)| geometry.non_finite_coords(&style))
+}
+
+fn feature_defs(feature: &Feature, style: &Style) -> Vec<String> {
+    styled_geometry(feature, style).map_or_else(Vec::new, |(geometry, style)| geometry.defs(&style))
+}
+
+/// Features are styled after their simplestyle properties (`fill`, `fill-opacity`, `stroke`,
+/// `stroke-width`, `stroke-opacity` and `marker-color`), which take precedence over the style of
+/// the [`Svg`](crate::Svg), and their `title` property is shown as a tooltip.
+///
+/// Features without a geometry are left out.
+impl ToSvgStr for Feature {
+    fn to_svg_str(&self, style: &Style) -> String {
+        feature_svg_str(self, style, title(self))
+    }
+
+    fn viewbox(&self, style: &Style) -> ViewBox {
+        feature_viewbox(self, style)
+    }
+
+    fn defs(&self, style: &Style) -> Vec<String> {
+        feature_defs(self, style)
+    }
+
+    fn non_finite_coords(&self, style: &Style) -> usize {
+        feature_non_finite_coords(self, style)
+    }
+}
+
+impl ToSvgStr for FeatureCollection {
+    fn to_svg_str(&self, style: &Style) -> String {
+        self.features.to_svg_str(style)
+    }
+
+    fn viewbox(&self, style: &Style) -> ViewBox {
+        self.features.viewbox(style)
+    }
+
+    fn defs(&self, style: &Style) -> Vec<String> {
+        self.features
+            .iter()
+            .flat_map(|feature| feature.defs(style))
+            .collect()
+    }
+
+    fn non_finite_coords(&self, style: &Style) -> usize {
+        self.features.non_finite_coords(style)
+    }
+}
+
+/// Styles the features from their properties, e.g. by `feature.property("kind")`.
+///
+/// The simplestyle properties of a feature still take precedence over the returned style.
+impl StyleEach<Feature> for FeatureCollection {
+    fn style_each<'a, F>(&self, style: F) -> StyledItems<'_, Feature, F>
+    where
+        F: Fn(usize, &Feature) -> Style<'a>,
+    {
+        self.features.style_each(style)
+    }
+
+    fn colormap_each(
+        &self,
+        colormap: Colormap,
+        normalization: Normalization,
+        value: impl Fn(&Feature) -> f64,
+    ) -> StyledItems<'_, Feature, impl Fn(usize, &Feature) -> Style<'static>> {
+        self.features.colormap_each(colormap, normalization, value)
+    }
+}
+
+/// Features rendered each with the tooltip returned for it by a closure, see [`TooltipEach`].
+pub struct Tooltips<'s, F> {
+    features: &'s [Feature],
+    tooltip: F,
+}
+
+/// Shows the tooltip returned by a closure for each feature instead of its `title` property,
+/// none being shown where the closure returns `None`.
+///
+/// Example:
+/// ```
+/// use geo_svg::{ToSvg, TooltipEach};
+/// use geojson::FeatureCollection;
+///
+/// let roads: FeatureCollection = r#"{"type": "FeatureCollection", "features": [{
+///     "type": "Feature",
+///     "geometry": {"type": "LineString", "coordinates": [[0, 0], [10, 5]]},
+///     "properties": {"name": "Main Street", "lanes": 2}
+/// }]}"#
+///     .parse()
+///     .unwrap();
+/// let tooltips = roads.tooltip_each(|road| {
+///     let name = road.property("name")?.as_str()?;
+///     Some(format!("{name} ({} lanes)", road.property("lanes")?))
+/// });
+/// let svg = tooltips.to_svg().with_stroke_width(0.5);
+/// ```
+pub trait TooltipEach {
+    fn tooltip_each<F>(&self, tooltip: F) -> Tooltips<'_, F>
+    where
+        F: Fn(&Feature) -> Option<String>;
+}
+
+impl TooltipEach for [Feature] {
+    fn tooltip_each<F>(&self, tooltip: F) -> Tooltips<'_, F>
+    where
+        F: Fn(&Feature) -> Option<String>,
+    {
+        Tooltips {
+            features: self,
+            tooltip,
+        }
+    }
+}
+
+impl TooltipEach for FeatureCollection {
+    fn tooltip_each<F>(&self, tooltip: F) -> Tooltips<'_, F>
+    where
+        F: Fn(&Feature) -> Option<String>,
+    {
+        self.features.tooltip_each(tooltip)
+    }
+}
+
+impl<F> ToSvgStr for Tooltips<'_, F>
+where
+    F: Fn(&Feature) -> Option<String>,
+{
+    fn to_svg_str(&self, style: &Style) -> String {
+        self.features
+            .iter()
+            .map(|feature| feature_svg_str(feature, style, (self.tooltip)(feature)))
+            .collect()
+    }
+
+    fn viewbox(&self, style: &Style) -> ViewBox {
+        self.features.viewbox(style)
+    }
+
+    fn defs(&self, style: &Style) -> Vec<String> {
+        self.features
+            .iter()
+            .flat_map(|feature| feature_defs(feature, style))
+            .collect()
+    }
+
+    fn non_finite_coords(&self, style: &Style) -> usize {
+        self.features.non_finite_coords(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ToSvg;
+
+    fn features() -> FeatureCollection {
+        r##"{"type": "FeatureCollection", "features": [
+            {
+                "type": "Feature",
+                "geometry": {"type": "Point", "coordinates": [0, 0]},
+                "properties": {"marker-color": "#ff0000", "fill": "blue", "title": "a < b"}
+            },
+            {
+                "type": "Feature",
+                "geometry": {"type": "LineString", "coordinates": [[0, 0], [10, 0]]},
+                "properties": {"stroke": "navy", "stroke-width": 2, "kind": "road"}
+            },
+            {"type": "Feature", "geometry": null, "properties": {"title": "nowhere"}}
+        ]}"##
+            .parse()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_simplestyle() {
+        let features = features();
+        let svg = features.to_svg().with_stroke_color(Color::BLACK);
+        assert_eq!(
+            svg.svg_str(),
+            r##"<g><title>a &lt; b</title><circle cx="0.0" cy="0.0" r="1" fill="#FF0000" stroke="black"/></g><path d="M 0.0 0.0 L 10.0 0.0" stroke="navy" stroke-width="2"/>"##
+        );
+        assert_eq!(svg.viewbox().max_x(), 12.0);
+    }
+
+    #[test]
+    fn test_style_each_and_tooltips() {
+        let features = features();
+        let styled = features.style_each(|_, feature| Style {
+            stroke_dasharray: (feature.property("kind").and_then(|kind| kind.as_str())
+                == Some("road"))
+            .then(|| vec![1.0, 1.0]),
+            ..Style::default()
+        });
+        assert!(
+            styled
+                .to_svg()
+                .svg_str()
+                .contains(r#"stroke-dasharray="1 1""#)
+        );
+
+        let tooltips = features
+            .tooltip_each(|feature| feature.property("kind").map(|kind| format!("kind: {kind}")));
+        let svg = tooltips.to_svg().svg_str();
+        assert!(svg.starts_with("<circle"));
+        assert!(svg.contains(r#"<g><title>kind: "road"</title><path"#));
+    }
+}
@@ -47,23 +47,78 @@
 //! [`ToSvg`]: svg/trait.ToSvg.html
 //! [`to_svg`]: svg/trait.ToSvg.html#method.to_svg
 
+mod aspect_ratio;
+mod cast;
+mod clip;
 mod color;
+mod colormap;
 mod combine;
+mod debug;
+mod defs;
+#[cfg(any(feature = "geojson", feature = "test-support"))]
+mod escape;
+mod geo_traits_impl;
+#[cfg(feature = "geojson")]
+mod geojson_impl;
+mod named_colors;
+mod non_finite;
+mod paint;
+mod palette;
+#[cfg(any(feature = "wkt", feature = "wkb"))]
+mod parse_error;
+mod path;
+mod pattern;
+mod pretty;
+mod projection;
+#[cfg(feature = "serde")]
+mod serde_impl;
+mod simplify;
 mod style;
+mod styled;
 mod svg;
 mod svg_impl;
+#[cfg(feature = "test-support")]
+pub mod test_support;
 mod text;
+mod theme;
 mod to_svg;
 mod to_svg_str;
 mod unit;
 mod viewbox;
+#[cfg(feature = "wkb")]
+mod wkb_impl;
+#[cfg(feature = "wkt")]
+mod wkt_impl;
 
+pub use aspect_ratio::{Align, AspectRatio};
 pub use color::*;
+pub use colormap::{Colormap, Normalization};
 pub use combine::*;
+pub use geo_traits_impl::AnyGeometry;
+#[cfg(feature = "geojson")]
+pub use geojson_impl::{TooltipEach, Tooltips};
+pub use non_finite::{NonFiniteError, NonFiniteItem};
+pub use paint::Paint;
+pub use palette::Palette;
+#[cfg(any(feature = "wkt", feature = "wkb"))]
+pub use parse_error::ParseGeometryError;
+pub use path::{PathEncoding, Shapes};
+pub use pattern::Pattern;
+pub use pretty::PrettyPrint;
+pub use projection::Projection;
+pub use simplify::{Simplification, SimplifyAlgorithm};
 pub use style::*;
+pub use styled::{StyleEach, StyledItems};
 pub use svg::Svg;
 pub use text::*;
+pub use theme::Theme;
 pub use to_svg::*;
 pub use to_svg_str::*;
 pub use unit::Unit;
 pub use viewbox::ViewBox;
+
+/// Items used by the expansion of [`dbg_svg!`], which are not part of the public API.
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::debug::{DBG_DIR_VAR, ToSvgs, debug_svg, write_debug_svg};
+}
@@ -0,0 +1,339 @@
+use crate::Color;
+
+/// The named colors of CSS, sorted by name, with their `0xRRGGBB` value.
+pub(crate) const NAMED_COLORS: [(&str, u32); 148] = [
+    ("aliceblue", 0xF0F8FF),
+    ("antiquewhite", 0xFAEBD7),
+    ("aqua", 0x00FFFF),
+    ("aquamarine", 0x7FFFD4),
+    ("azure", 0xF0FFFF),
+    ("beige", 0xF5F5DC),
+    ("bisque", 0xFFE4C4),
+    ("black", 0x000000),
+    ("blanchedalmond", 0xFFEBCD),
+    ("blue", 0x0000FF),
+    ("blueviolet", 0x8A2BE2),
+    ("brown", 0xA52A2A),
+    ("burlywood", 0xDEB887),
+    ("cadetblue", 0x5F9EA0),
+    ("chartreuse", 0x7FFF00),
+    ("chocolate", 0xD2691E),
+    ("coral", 0xFF7F50),
+    ("cornflowerblue", 0x6495ED),
+    ("cornsilk", 0xFFF8DC),
+    ("crimson", 0xDC143C),
+    ("cyan", 0x00FFFF),
+    ("darkblue", 0x00008B),
+    ("darkcyan", 0x008B8B),
+    ("darkgoldenrod", 0xB8860B),
+    ("darkgray", 0xA9A9A9),
+    ("darkgreen", 0x006400),
+    ("darkgrey", 0xA9A9A9),
+    ("darkkhaki", 0xBDB76B),
+    ("darkmagenta", 0x8B008B),
+    ("darkolivegreen", 0x556B2F),
+    ("darkorange", 0xFF8C00),
+    ("darkorchid", 0x9932CC),
+    ("darkred", 0x8B0000),
+    ("darksalmon", 0xE9967A),
+    ("darkseagreen", 0x8FBC8F),
+    ("darkslateblue", 0x483D8B),
+    ("darkslategray", 0x2F4F4F),
+    ("darkslategrey", 0x2F4F4F),
+    ("darkturquoise", 0x00CED1),
+    ("darkviolet", 0x9400D3),
+    ("deeppink", 0xFF1493),
+    ("deepskyblue", 0x00BFFF),
+    ("dimgray", 0x696969),
+    ("dimgrey", 0x696969),
+    ("dodgerblue", 0x1E90FF),
+    ("firebrick", 0xB22222),
+    ("floralwhite", 0xFFFAF0),
+    ("forestgreen", 0x228B22),
+    ("fuchsia", 0xFF00FF),
+    ("gainsboro", 0xDCDCDC),
+    ("ghostwhite", 0xF8F8FF),
+    ("gold", 0xFFD700),
+    ("goldenrod", 0xDAA520),
+    ("gray", 0x808080),
+    ("green", 0x008000),
+    ("greenyellow", 0xADFF2F),
+    ("grey", 0x808080),
+    ("honeydew", 0xF0FFF0),
+    ("hotpink", 0xFF69B4),
+    ("indianred", 0xCD5C5C),
+    ("indigo", 0x4B0082),
+    ("ivory", 0xFFFFF0),
+    ("khaki", 0xF0E68C),
+    ("lavender", 0xE6E6FA),
+    ("lavenderblush", 0xFFF0F5),
+    ("lawngreen", 0x7CFC00),
+    ("lemonchiffon", 0xFFFACD),
+    ("lightblue", 0xADD8E6),
+    ("lightcoral", 0xF08080),
+    ("lightcyan", 0xE0FFFF),
+    ("lightgoldenrodyellow", 0xFAFAD2),
+    ("lightgray", 0xD3D3D3),
+    ("lightgreen", 0x90EE90),
+    ("lightgrey", 0xD3D3D3),
+    ("lightpink", 0xFFB6C1),
+    ("lightsalmon", 0xFFA07A),
+    ("lightseagreen", 0x20B2AA),
+    ("lightskyblue", 0x87CEFA),
+    ("lightslategray", 0x778899),
+    ("lightslategrey", 0x778899),
+    ("lightsteelblue", 0xB0C4DE),
+    ("lightyellow", 0xFFFFE0),
+    ("lime", 0x00FF00),
+    ("limegreen", 0x32CD32),
+    ("linen", 0xFAF0E6),
+    ("magenta", 0xFF00FF),
+    ("maroon", 0x800000),
+    ("mediumaquamarine", 0x66CDAA),
+    ("mediumblue", 0x0000CD),
+    ("mediumorchid", 0xBA55D3),
+    ("mediumpurple", 0x9370DB),
+    ("mediumseagreen", 0x3CB371),
+    ("mediumslateblue", 0x7B68EE),
+    ("mediumspringgreen", 0x00FA9A),
+    ("mediumturquoise", 0x48D1CC),
+    ("mediumvioletred", 0xC71585),
+    ("midnightblue", 0x191970),
+    ("mintcream", 0xF5FFFA),
+    ("mistyrose", 0xFFE4E1),
+    ("moccasin", 0xFFE4B5),
+    ("navajowhite", 0xFFDEAD),
+    ("navy", 0x000080),
+    ("oldlace", 0xFDF5E6),
+    ("olive", 0x808000),
+    ("olivedrab", 0x6B8E23),
+    ("orange", 0xFFA500),
+    ("orangered", 0xFF4500),
+    ("orchid", 0xDA70D6),
+    ("palegoldenrod", 0xEEE8AA),
+    ("palegreen", 0x98FB98),
+    ("paleturquoise", 0xAFEEEE),
+    ("palevioletred", 0xDB7093),
+    ("papayawhip", 0xFFEFD5),
+    ("peachpuff", 0xFFDAB9),
+    ("peru", 0xCD853F),
+    ("pink", 0xFFC0CB),
+    ("plum", 0xDDA0DD),
+    ("powderblue", 0xB0E0E6),
+    ("purple", 0x800080),
+    ("rebeccapurple", 0x663399),
+    ("red", 0xFF0000),
+    ("rosybrown", 0xBC8F8F),
+    ("royalblue", 0x4169E1),
+    ("saddlebrown", 0x8B4513),
+    ("salmon", 0xFA8072),
+    ("sandybrown", 0xF4A460),
+    ("seagreen", 0x2E8B57),
+    ("seashell", 0xFFF5EE),
+    ("sienna", 0xA0522D),
+    ("silver", 0xC0C0C0),
+    ("skyblue", 0x87CEEB),
+    ("slateblue", 0x6A5ACD),
+    ("slategray", 0x708090),
+    ("slategrey", 0x708090),
+    ("snow", 0xFFFAFA),
+    ("springgreen", 0x00FF7F),
+    ("steelblue", 0x4682B4),
+    ("tan", 0xD2B48C),
+    ("teal", 0x008080),
+    ("thistle", 0xD8BFD8),
+    ("tomato", 0xFF6347),
+    ("turquoise", 0x40E0D0),
+    ("violet", 0xEE82EE),
+    ("wheat", 0xF5DEB3),
+    ("white", 0xFFFFFF),
+    ("whitesmoke", 0xF5F5F5),
+    ("yellow", 0xFFFF00),
+    ("yellowgreen", 0x9ACD32),
+];
+
+/// The entry of the named color `name`, ignoring case.
+pub(crate) fn find(name: &str) -> Option<(&'static str, u32)> {
+    let name = name.to_ascii_lowercase();
+    NAMED_COLORS
+        .binary_search_by(|(other, _)| other.cmp(&name.as_str()))
+        .ok()
+        .map(|index| NAMED_COLORS[index])
+}
+
+/// The named colors of CSS.
+impl Color<'static> {
+    pub const ALICE_BLUE: Color<'static> = Color::Named("aliceblue");
+    pub const ANTIQUE_WHITE: Color<'static> = Color::Named("antiquewhite");
+    pub const AQUA: Color<'static> = Color::Named("aqua");
+    pub const AQUAMARINE: Color<'static> = Color::Named("aquamarine");
+    pub const AZURE: Color<'static> = Color::Named("azure");
+    pub const BEIGE: Color<'static> = Color::Named("beige");
+    pub const BISQUE: Color<'static> = Color::Named("bisque");
+    pub const BLACK: Color<'static> = Color::Named("black");
+    pub const BLANCHED_ALMOND: Color<'static> = Color::Named("blanchedalmond");
+    pub const BLUE: Color<'static> = Color::Named("blue");
+    pub const BLUE_VIOLET: Color<'static> = Color::Named("blueviolet");
+    pub const BROWN: Color<'static> = Color::Named("brown");
+    pub const BURLYWOOD: Color<'static> = Color::Named("burlywood");
+    pub const CADET_BLUE: Color<'static> = Color::Named("cadetblue");
+    pub const CHARTREUSE: Color<'static> = Color::Named("chartreuse");
+    pub const CHOCOLATE: Color<'static> = Color::Named("chocolate");
+    pub const CORAL: Color<'static> = Color::Named("coral");
+    pub const CORNFLOWER_BLUE: Color<'static> = Color::Named("cornflowerblue");
+    pub const CORNSILK: Color<'static> = Color::Named("cornsilk");
+    pub const CRIMSON: Color<'static> = Color::Named("crimson");
+    pub const CYAN: Color<'static> = Color::Named("cyan");
+    pub const DARK_BLUE: Color<'static> = Color::Named("darkblue");
+    pub const DARK_CYAN: Color<'static> = Color::Named("darkcyan");
+    pub const DARK_GOLDENROD: Color<'static> = Color::Named("darkgoldenrod");
+    pub const DARK_GRAY: Color<'static> = Color::Named("darkgray");
+    pub const DARK_GREEN: Color<'static> = Color::Named("darkgreen");
+    pub const DARK_GREY: Color<'static> = Color::Named("darkgrey");
+    pub const DARK_KHAKI: Color<'static> = Color::Named("darkkhaki");
+    pub const DARK_MAGENTA: Color<'static> = Color::Named("darkmagenta");
+    pub const DARK_OLIVE_GREEN: Color<'static> = Color::Named("darkolivegreen");
+    pub const DARK_ORANGE: Color<'static> = Color::Named("darkorange");
+    pub const DARK_ORCHID: Color<'static> = Color::Named("darkorchid");
+    pub const DARK_RED: Color<'static> = Color::Named("darkred");
+    pub const DARK_SALMON: Color<'static> = Color::Named("darksalmon");
+    pub const DARK_SEA_GREEN: Color<'static> = Color::Named("darkseagreen");
+    pub const DARK_SLATE_BLUE: Color<'static> = Color::Named("darkslateblue");
+    pub const DARK_SLATE_GRAY: Color<'static> = Color::Named("darkslategray");
+    pub const DARK_SLATE_GREY: Color<'static> = Color::Named("darkslategrey");
+    pub const DARK_TURQUOISE: Color<'static> = Color::Named("darkturquoise");
+    pub const DARK_VIOLET: Color<'static> = Color::Named("darkviolet");
+    pub const DEEP_PINK: Color<'static> = Color::Named("deeppink");
+    pub const DEEP_SKY_BLUE: Color<'static> = Color::Named("deepskyblue");
+    pub const DIM_GRAY: Color<'static> = Color::Named("dimgray");
+    pub const DIM_GREY: Color<'static> = Color::Named("dimgrey");
+    pub const DODGER_BLUE: Color<'static> = Color::Named("dodgerblue");
+    pub const FIREBRICK: Color<'static> = Color::Named("firebrick");
+    pub const FLORAL_WHITE: Color<'static> = Color::Named("floralwhite");
+    pub const FOREST_GREEN: Color<'static> = Color::Named("forestgreen");
+    pub const FUCHSIA: Color<'static> = Color::Named("fuchsia");
+    pub const GAINSBORO: Color<'static> = Color::Named("gainsboro");
+    pub const GHOST_WHITE: Color<'static> = Color::Named("ghostwhite");
+    pub const GOLD: Color<'static> = Color::Named("gold");
+    pub const GOLDENROD: Color<'static> = Color::Named("goldenrod");
+    pub const GRAY: Color<'static> = Color::Named("gray");
+    pub const GREEN: Color<'static> = Color::Named("green");
+    pub const GREEN_YELLOW: Color<'static> = Color::Named("greenyellow");
+    pub const GREY: Color<'static> = Color::Named("grey");
+    pub const HONEYDEW: Color<'static> = Color::Named("honeydew");
+    pub const HOT_PINK: Color<'static> = Color::Named("hotpink");
+    pub const INDIAN_RED: Color<'static> = Color::Named("indianred");
+    pub const INDIGO: Color<'static> = Color::Named("indigo");
+    pub const IVORY: Color<'static> = Color::Named("ivory");
+    pub const KHAKI: Color<'static> = Color::Named("khaki");
+    pub const LAVENDER: Color<'static> = Color::Named("lavender");
+    pub const LAVENDER_BLUSH: Color<'static> = Color::Named("lavenderblush");
+    pub const LAWN_GREEN: Color<'static> = Color::Named("lawngreen");
+    pub const LEMON_CHIFFON: Color<'static> = Color::Named("lemonchiffon");
+    pub const LIGHT_BLUE: Color<'static> = Color::Named("lightblue");
+    pub const LIGHT_CORAL: Color<'static> = Color::Named("lightcoral");
+    pub const LIGHT_CYAN: Color<'static> = Color::Named("lightcyan");
+    pub const LIGHT_GOLDENROD_YELLOW: Color<'static> = Color::Named("lightgoldenrodyellow");
+    pub const LIGHT_GRAY: Color<'static> = Color::Named("lightgray");
+    pub const LIGHT_GREEN: Color<'static> = Color::Named("lightgreen");
+    pub const LIGHT_GREY: Color<'static> = Color::Named("lightgrey");
+    pub const LIGHT_PINK: Color<'static> = Color::Named("lightpink");
+    pub const LIGHT_SALMON: Color<'static> = Color::Named("lightsalmon");
+    pub const LIGHT_SEA_GREEN: Color<'static> = Color::Named("lightseagreen");
+    pub const LIGHT_SKY_BLUE: Color<'static> = Color::Named("lightskyblue");
+    pub const LIGHT_SLATE_GRAY: Color<'static> = Color::Named("lightslategray");
+    pub const LIGHT_SLATE_GREY: Color<'static> = Color::Named("lightslategrey");
+    pub const LIGHT_STEEL_BLUE: Color<'static> = Color::Named("lightsteelblue");
+    pub const LIGHT_YELLOW: Color<'static> = Color::Named("lightyellow");
+    pub const LIME: Color<'static> = Color::Named("lime");
+    pub const LIME_GREEN: Color<'static> = Color::Named("limegreen");
+    pub const LINEN: Color<'static> = Color::Named("linen");
+    pub const MAGENTA: Color<'static> = Color::Named("magenta");
+    pub const MAROON: Color<'static> = Color::Named("maroon");
+    pub const MEDIUM_AQUAMARINE: Color<'static> = Color::Named("mediumaquamarine");
+    pub const MEDIUM_BLUE: Color<'static> = Color::Named("mediumblue");
+    pub const MEDIUM_ORCHID: Color<'static> = Color::Named("mediumorchid");
+    pub const MEDIUM_PURPLE: Color<'static> = Color::Named("mediumpurple");
+    pub const MEDIUM_SEA_GREEN: Color<'static> = Color::Named("mediumseagreen");
+    pub const MEDIUM_SLATE_BLUE: Color<'static> = Color::Named("mediumslateblue");
+    pub const MEDIUM_SPRING_GREEN: Color<'static> = Color::Named("mediumspringgreen");
+    pub const MEDIUM_TURQUOISE: Color<'static> = Color::Named("mediumturquoise");
+    pub const MEDIUM_VIOLET_RED: Color<'static> = Color::Named("mediumvioletred");
+    pub const MIDNIGHT_BLUE: Color<'static> = Color::Named("midnightblue");
+    pub const MINT_CREAM: Color<'static> = Color::Named("mintcream");
+    pub const MISTY_ROSE: Color<'static> = Color::Named("mistyrose");
+    pub const MOCCASIN: Color<'static> = Color::Named("moccasin");
+    pub const NAVAJO_WHITE: Color<'static> = Color::Named("navajowhite");
+    pub const NAVY: Color<'static> = Color::Named("navy");
+    pub const OLD_LACE: Color<'static> = Color::Named("oldlace");
+    pub const OLIVE: Color<'static> = Color::Named("olive");
+    pub const OLIVE_DRAB: Color<'static> = Color::Named("olivedrab");
+    pub const ORANGE: Color<'static> = Color::Named("orange");
+    pub const ORANGE_RED: Color<'static> = Color::Named("orangered");
+    pub const ORCHID: Color<'static> = Color::Named("orchid");
+    pub const PALE_GOLDENROD: Color<'static> = Color::Named("palegoldenrod");
+    pub const PALE_GREEN: Color<'static> = Color::Named("palegreen");
+    pub const PALE_TURQUOISE: Color<'static> = Color::Named("paleturquoise");
+    pub const PALE_VIOLET_RED: Color<'static> = Color::Named("palevioletred");
+    pub const PAPAYA_WHIP: Color<'static> = Color::Named("papayawhip");
+    pub const PEACH_PUFF: Color<'static> = Color::Named("peachpuff");
+    pub const PERU: Color<'static> = Color::Named("peru");
+    pub const PINK: Color<'static> = Color::Named("pink");
+    pub const PLUM: Color<'static> = Color::Named("plum");
+    pub const POWDER_BLUE: Color<'static> = Color::Named("powderblue");
+    pub const PURPLE: Color<'static> = Color::Named("purple");
+    pub const REBECCA_PURPLE: Color<'static> = Color::Named("rebeccapurple");
+    pub const RED: Color<'static> = Color::Named("red");
+    pub const ROSY_BROWN: Color<'static> = Color::Named("rosybrown");
+    pub const ROYAL_BLUE: Color<'static> = Color::Named("royalblue");
+    pub const SADDLE_BROWN: Color<'static> = Color::Named("saddlebrown");
+    pub const SALMON: Color<'static> = Color::Named("salmon");
+    pub const SANDY_BROWN: Color<'static> = Color::Named("sandybrown");
+    pub const SEA_GREEN: Color<'static> = Color::Named("seagreen");
+    pub const SEASHELL: Color<'static> = Color::Named("seashell");
+    pub const SIENNA: Color<'static> = Color::Named("sienna");
+    pub const SILVER: Color<'static> = Color::Named("silver");
+    pub const SKY_BLUE: Color<'static> = Color::Named("skyblue");
+    pub const SLATE_BLUE: Color<'static> = Color::Named("slateblue");
+    pub const SLATE_GRAY: Color<'static> = Color::Named("slategray");
+    pub const SLATE_GREY: Color<'static> = Color::Named("slategrey");
+    pub const SNOW: Color<'static> = Color::Named("snow");
+    pub const SPRING_GREEN: Color<'static> = Color::Named("springgreen");
+    pub const STEEL_BLUE: Color<'static> = Color::Named("steelblue");
+    pub const TAN: Color<'static> = Color::Named("tan");
+    pub const TEAL: Color<'static> = Color::Named("teal");
+    pub const THISTLE: Color<'static> = Color::Named("thistle");
+    pub const TOMATO: Color<'static> = Color::Named("tomato");
+    pub const TURQUOISE: Color<'static> = Color::Named("turquoise");
+    pub const VIOLET: Color<'static> = Color::Named("violet");
+    pub const WHEAT: Color<'static> = Color::Named("wheat");
+    pub const WHITE: Color<'static> = Color::Named("white");
+    pub const WHITE_SMOKE: Color<'static> = Color::Named("whitesmoke");
+    pub const YELLOW: Color<'static> = Color::Named("yellow");
+    pub const YELLOW_GREEN: Color<'static> = Color::Named("yellowgreen");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sorted() {
+        assert!(NAMED_COLORS.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+
+    #[test]
+    fn test_constants() {
+        assert_eq!(
+            Color::LIGHT_GOLDENROD_YELLOW,
+            Color::Named("lightgoldenrodyellow")
+        );
+        assert_eq!(Color::REBECCA_PURPLE.to_rgb(), Some([0x66, 0x33, 0x99]));
+    }
+
+    #[test]
+    fn test_find() {
+        assert_eq!(find("RebeccaPurple"), Some(("rebeccapurple", 0x663399)));
+        assert_eq!(find("reddish"), None);
+    }
+}
@@ -0,0 +1,61 @@
+use crate::Style;
+use crate::projection::project;
+use geo_types::{Coord, CoordNum};
+use num_traits::NumCast;
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result};
+
+/// Item of an [`Svg`](crate::Svg) holding non-finite coordinates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NonFiniteItem {
+    /// Index of the node holding the item, in pre-order from the root.
+    pub node: usize,
+    /// Index of the item within its node.
+    pub item: usize,
+    /// Number of non-finite coordinates of the item once projected.
+    pub coords: usize,
+}
+
+impl Display for NonFiniteItem {
+    fn fmt(&self, fmt: &mut Formatter) -> Result {
+        write!(
+            fmt,
+            "{coords} non-finite coordinate(s) in item {item} of node {node}",
+            coords = self.coords,
+            item = self.item,
+            node = self.node,
+        )
+    }
+}
+
+/// Error returned by [`Svg::try_to_string`](crate::Svg::try_to_string) when some items hold
+/// non-finite coordinates.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NonFiniteError {
+    pub items: Vec<NonFiniteItem>,
+}
+
+impl Display for NonFiniteError {
+    fn fmt(&self, fmt: &mut Formatter) -> Result {
+        let items: Vec<String> = self.items.iter().map(ToString::to_string).collect();
+        write!(fmt, "{}", items.join(", "))
+    }
+}
+
+impl Error for NonFiniteError {}
+
+pub(crate) fn is_finite<T: CoordNum>(coord: Coord<T>) -> bool {
+    let finite = |value: T| NumCast::from(value).is_some_and(f64::is_finite);
+    finite(coord.x) && finite(coord.y)
+}
+
+/// Number of coordinates which are not finite once projected.
+pub(crate) fn non_finite<T: CoordNum>(
+    coords: impl IntoIterator<Item = Coord<T>>,
+    style: &Style,
+) -> usize {
+    coords
+        .into_iter()
+        .filter(|coord| !is_finite(project(style, *coord)))
+        .count()
+}
@@ -0,0 +1,40 @@
+use crate::{Color, Pattern};
+use std::fmt::{Display, Formatter, Result};
+
+/// What the inside of a shape is painted with.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Paint<'a> {
+    Color(Color<'a>),
+    Pattern(Pattern<'a>),
+}
+
+impl Paint<'_> {
+    /// Definitions this paint refers to, to be written in `<defs>`.
+    pub fn defs(&self) -> Vec<String> {
+        match self {
+            Paint::Color(_) => vec![],
+            Paint::Pattern(pattern) => vec![pattern.to_svg_def()],
+        }
+    }
+}
+
+impl<'a> From<Color<'a>> for Paint<'a> {
+    fn from(color: Color<'a>) -> Self {
+        Paint::Color(color)
+    }
+}
+
+impl<'a> From<Pattern<'a>> for Paint<'a> {
+    fn from(pattern: Pattern<'a>) -> Self {
+        Paint::Pattern(pattern)
+    }
+}
+
+impl Display for Paint<'_> {
+    fn fmt(&self, fmt: &mut Formatter) -> Result {
+        match self {
+            Paint::Color(color) => write!(fmt, "{color}"),
+            Paint::Pattern(pattern) => write!(fmt, "url(#{})", pattern.id()),
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use crate::Color;
+
+/// Categorical color palettes, to tell apart the elements of a combined SVG.
+///
+/// Example:
+/// ```
+/// use geo_types::Point;
+/// use geo_svg::{CombineToSVG, Palette};
+///
+/// let points = vec![Point::new(0.0, 0.0), Point::new(1.0, 1.0)];
+/// let svg = points.combine_to_svg_with_palette(Palette::OkabeIto);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Palette {
+    /// The default palette of Tableau, 10 colors.
+    #[default]
+    Tableau10,
+    /// ColorBrewer qualitative set of 8 colors.
+    Accent,
+    /// ColorBrewer qualitative set of 8 dark colors.
+    Dark2,
+    /// ColorBrewer qualitative set of 6 pairs of light and dark colors.
+    Paired,
+    /// ColorBrewer qualitative set of 9 pastel colors.
+    Pastel1,
+    /// ColorBrewer qualitative set of 8 pastel colors.
+    Pastel2,
+    /// ColorBrewer qualitative set of 9 bright colors.
+    Set1,
+    /// ColorBrewer qualitative set of 8 colors.
+    Set2,
+    /// ColorBrewer qualitative set of 12 colors.
+    Set3,
+    /// The 8 colors of Okabe and Ito, distinguishable by people with color blindness.
+    OkabeIto,
+}
+
+impl Palette {
+    /// Colors of the palette, as `0xRRGGBB`.
+    pub fn colors(&self) -> &'static [u32] {
+        match self {
+            Palette::Tableau10 => &[
+                0x4E79A7, 0xF28E2B, 0xE15759, 0x76B7B2, 0x59A14F, 0xEDC948, 0xB07AA1, 0xFF9DA7,
+                0x9C755F, 0xBAB0AC,
+            ],
+            Palette::Accent => &[
+                0x7FC97F, 0xBEAED4, 0xFDC086, 0xFFFF99, 0x386CB0, 0xF0027F, 0xBF5B17, 0x666666,
+            ],
+            Palette::Dark2 => &[
+                0x1B9E77, 0xD95F02, 0x7570B3, 0xE7298A, 0x66A61E, 0xE6AB02, 0xA6761D, 0x666666,
+            ],
+            Palette::Paired => &[
+                0xA6CEE3, 0x1F78B4, 0xB2DF8A, 0x33A02C, 0xFB9A99, 0xE31A1C, 0xFDBF6F, 0xFF7F00,
+                0xCAB2D6, 0x6A3D9A, 0xFFFF99, 0xB15928,
+            ],
+            Palette::Pastel1 => &[
+                0xFBB4AE, 0xB3CDE3, 0xCCEBC5, 0xDECBE4, 0xFED9A6, 0xFFFFCC, 0xE5D8BD, 0xFDDAEC,
+                0xF2F2F2,
+            ],
+            Palette::Pastel2 => &[
+                0xB3E2CD, 0xFDCDAC, 0xCBD5E8, 0xF4CAE4, 0xE6F5C9, 0xFFF2AE, 0xF1E2CC, 0xCCCCCC,
+            ],
+            Palette::Set1 => &[
+                0xE41A1C, 0x377EB8, 0x4DAF4A, 0x984EA3, 0xFF7F00, 0xFFFF33, 0xA65628, 0xF781BF,
+                0x999999,
+            ],
+            Palette::Set2 => &[
+                0x66C2A5, 0xFC8D62, 0x8DA0CB, 0xE78AC3, 0xA6D854, 0xFFD92F, 0xE5C494, 0xB3B3B3,
+            ],
+            Palette::Set3 => &[
+                0x8DD3C7, 0xFFFFB3, 0xBEBADA, 0xFB8072, 0x80B1D3, 0xFDB462, 0xB3DE69, 0xFCCDE5,
+                0xD9D9D9, 0xBC80BD, 0xCCEBC5, 0xFFED6F,
+            ],
+            Palette::OkabeIto => &[
+                0xE69F00, 0x56B4E9, 0x009E73, 0xF0E442, 0x0072B2, 0xD55E00, 0xCC79A7, 0x000000,
+            ],
+        }
+    }
+
+    /// The `index`-th color of the palette, starting over once all colors have been used.
+    pub fn color(&self, index: usize) -> Color<'static> {
+        let colors = self.colors();
+        Color::Hex(colors[index % colors.len()])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_color() {
+        assert_eq!(Palette::Tableau10.color(0), Color::Hex(0x4E79A7));
+        assert_eq!(Palette::OkabeIto.color(9), Color::Hex(0x56B4E9));
+        assert_eq!(Palette::Set3.colors().len(), 12);
+    }
+}
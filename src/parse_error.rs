@@ -0,0 +1,25 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result};
+
+/// Error returned when geometries cannot be parsed from their WKT or WKB encoding, see
+/// [`Svg::from_wkt_str`](crate::Svg::from_wkt_str) and
+/// [`Svg::from_wkb_bytes`](crate::Svg::from_wkb_bytes).
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum ParseGeometryError {
+    /// The text is not valid WKT.
+    Wkt(String),
+    /// The bytes are not valid WKB.
+    Wkb(String),
+}
+
+impl Display for ParseGeometryError {
+    fn fmt(&self, fmt: &mut Formatter) -> Result {
+        match self {
+            ParseGeometryError::Wkt(reason) => write!(fmt, "invalid WKT: {reason}"),
+            ParseGeometryError::Wkb(reason) => write!(fmt, "invalid WKB: {reason}"),
+        }
+    }
+}
+
+impl Error for ParseGeometryError {}
@@ -0,0 +1,291 @@
+use geo_types::{Coord, CoordNum};
+
+/// How the `d` attribute of paths is written.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum PathEncoding {
+    /// Absolute `M x y L x y` commands separated by spaces, easy to read.
+    #[default]
+    Absolute,
+    /// Whichever of absolute or relative commands is shorter, `H`/`V` for horizontal and
+    /// vertical segments, implicit repeated commands and no superfluous separators, roughly
+    /// halving the size of the output.
+    Compact,
+}
+
+/// Which elements geometries are written as.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Shapes {
+    /// `<path>` elements, rectangles and triangles being written as polygons.
+    #[default]
+    Paths,
+    /// `<rect>`, `<line>`, `<polygon>` and `<polyline>` elements where possible, which are easier
+    /// to edit in tools such as Inkscape. Polygons with holes are still written as paths, and so
+    /// are projected rectangles, which are no longer rectangles.
+    Primitives,
+}
+
+/// Compact path data of `subpaths`, closed with `z` if `closed`.
+pub(crate) fn compact<T: CoordNum>(subpaths: &[Vec<Coord<T>>], closed: bool) -> String {
+    let mut path = CompactPath::default();
+    let mut current: Option<Coord<T>> = None;
+    for subpath in subpaths {
+        let mut coords = subpath.iter().copied();
+        let Some(start) = coords.next() else {
+            continue;
+        };
+        path.move_to(start, current);
+        current = Some(start);
+        for coord in coords {
+            path.line_to(coord, current.unwrap_or(start));
+            current = Some(coord);
+        }
+        if closed {
+            path.command('z');
+            current = Some(start);
+        }
+    }
+    path.data
+}
+
+#[derive(Default)]
+struct CompactPath {
+    data: String,
+    last_command: Option<char>,
+    last_number_has_dot: bool,
+}
+
+impl CompactPath {
+    fn move_to<T: CoordNum>(&mut self, to: Coord<T>, from: Option<Coord<T>>) {
+        let absolute = ('M', vec![number(to.x), number(to.y)]);
+        let candidates = match from {
+            Some(from) => vec![
+                absolute,
+                ('m', vec![delta(to.x, from.x), delta(to.y, from.y)]),
+            ],
+            None => vec![absolute],
+        };
+        self.shortest(candidates, false);
+    }
+
+    fn line_to<T: CoordNum>(&mut self, to: Coord<T>, from: Coord<T>) {
+        let candidates = if to.y == from.y {
+            vec![('h', vec![delta(to.x, from.x)]), ('H', vec![number(to.x)])]
+        } else if to.x == from.x {
+            vec![('v', vec![delta(to.y, from.y)]), ('V', vec![number(to.y)])]
+        } else {
+            vec![
+                ('l', vec![delta(to.x, from.x), delta(to.y, from.y)]),
+                ('L', vec![number(to.x), number(to.y)]),
+            ]
+        };
+        self.shortest(candidates, true);
+    }
+
+    /// Writes the candidate command taking the fewest characters, the first one on ties,
+    /// omitting the command letter when it repeats the previous one.
+    fn shortest(&mut self, candidates: Vec<(char, Vec<String>)>, repeatable: bool) {
+        let length = |(command, numbers): &(char, Vec<String>)| {
+            let letter = usize::from(!repeatable || self.last_command != Some(*command));
+            letter + numbers.iter().map(|number| number.len() + 1).sum::<usize>()
+        };
+        let (command, numbers) = candidates
+            .into_iter()
+            .min_by_key(|candidate| length(candidate))
+            .expect("at least one candidate");
+        if !repeatable || self.last_command != Some(command) {
+            self.command(command);
+        }
+        for number in numbers {
+            self.number(&number);
+        }
+    }
+
+    fn command(&mut self, command: char) {
+        self.data.push(command);
+        self.last_command = Some(command);
+        self.last_number_has_dot = false;
+    }
+
+    fn number(&mut self, number: &str) {
+        let needs_separator = match self.data.chars().last() {
+            None => false,
+            Some(last) if last.is_ascii_alphabetic() => false,
+            Some(_) => {
+                !(number.starts_with('-') || (number.starts_with('.') && self.last_number_has_dot))
+            }
+        };
+        if needs_separator {
+            self.data.push(' ');
+        }
+        self.data.push_str(number);
+        self.last_number_has_dot = number.contains('.') && !number.contains('e');
+    }
+}
+
+/// Shortest representation of a coordinate, without trailing `.0` nor leading `0`.
+fn number<T: CoordNum>(value: T) -> String {
+    let number = format!("{value:?}");
+    let number = number.strip_suffix(".0").unwrap_or(&number);
+    if let Some(fraction) = number.strip_prefix("0.") {
+        format!(".{fraction}")
+    } else if let Some(fraction) = number.strip_prefix("-0.") {
+        format!("-.{fraction}")
+    } else if number == "-0" {
+        "0".to_string()
+    } else {
+        number.to_string()
+    }
+}
+
+/// Shortest representation of `to - from`, which is negated rather than computed as is when
+/// negative, so that it does not overflow unsigned types.
+fn delta<T: CoordNum>(to: T, from: T) -> String {
+    if to >= from {
+        number(to - from)
+    } else {
+        format!("-{}", number(from - to))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Absolute vertices of each subpath of the path data `d`.
+    fn parse(d: &str) -> Vec<Vec<Coord<f64>>> {
+        let mut tokens = vec![];
+        let mut chars = d.chars().peekable();
+        while let Some(&c) = chars.peek() {
+            if c.is_ascii_alphabetic() && c != 'e' {
+                tokens.push(c.to_string());
+                chars.next();
+            } else if c == ' ' || c == ',' {
+                chars.next();
+            } else {
+                let mut number = String::from(c);
+                chars.next();
+                while let Some(&c) = chars.peek() {
+                    let starts_new = c == '-' && !number.ends_with('e')
+                        || c == '.' && number.contains('.')
+                        || c == ' '
+                        || c.is_ascii_alphabetic() && c != 'e';
+                    if starts_new {
+                        break;
+                    }
+                    number.push(c);
+                    chars.next();
+                }
+                tokens.push(number);
+            }
+        }
+        let mut subpaths: Vec<Vec<Coord<f64>>> = vec![];
+        let mut current = Coord { x: 0.0, y: 0.0 };
+        let mut start = current;
+        let mut command = 'M';
+        let mut tokens = tokens.into_iter().peekable();
+        while let Some(token) = tokens.peek().cloned() {
+            if let Some(letter) = token.chars().next().filter(char::is_ascii_alphabetic) {
+                tokens.next();
+                command = letter;
+                if command.eq_ignore_ascii_case(&'z') {
+                    current = start;
+                    continue;
+                }
+            }
+            let mut next = || tokens.next().unwrap().parse::<f64>().unwrap();
+            current = match command {
+                'M' | 'L' => Coord {
+                    x: next(),
+                    y: next(),
+                },
+                'm' | 'l' => {
+                    current
+                        + Coord {
+                            x: next(),
+                            y: next(),
+                        }
+                }
+                'H' => Coord {
+                    x: next(),
+                    ..current
+                },
+                'h' => current + Coord { x: next(), y: 0.0 },
+                'V' => Coord {
+                    y: next(),
+                    ..current
+                },
+                'v' => current + Coord { x: 0.0, y: next() },
+                _ => panic!("unexpected command {command}"),
+            };
+            if command.eq_ignore_ascii_case(&'m') {
+                start = current;
+                subpaths.push(vec![]);
+                command = if command == 'm' { 'l' } else { 'L' };
+            }
+            subpaths.last_mut().unwrap().push(current);
+        }
+        subpaths
+    }
+
+    #[test]
+    fn test_number() {
+        assert_eq!(number(10.0), "10");
+        assert_eq!(number(0.5), ".5");
+        assert_eq!(number(-0.25), "-.25");
+        assert_eq!(number(-3), "-3");
+    }
+
+    #[test]
+    fn test_compact() {
+        let square = vec![
+            Coord { x: 210.0, y: 0.0 },
+            Coord { x: 300.0, y: 0.0 },
+            Coord { x: 300.0, y: 90.0 },
+            Coord { x: 210.0, y: 90.0 },
+        ];
+        assert_eq!(compact(&[square], true), "M210 0h90v90h-90z");
+        let line = vec![
+            Coord { x: 0.5, y: 0.5 },
+            Coord { x: 1.5, y: 1.5 },
+            Coord { x: 2.5, y: 2.0 },
+        ];
+        assert_eq!(compact(&[line], false), "M.5.5l1 1 1 .5");
+        let unsigned: Vec<Coord<u32>> = vec![
+            Coord { x: 300, y: 90 },
+            Coord { x: 210, y: 90 },
+            Coord { x: 200, y: 0 },
+        ];
+        let subpaths = [unsigned.clone(), unsigned];
+        assert_eq!(
+            compact(&subpaths, false),
+            "M300 90h-90L200 0M300 90h-90L200 0"
+        );
+    }
+
+    #[test]
+    fn test_compact_is_geometrically_equivalent() {
+        let subpaths = vec![
+            vec![
+                Coord {
+                    x: 114.19,
+                    y: 22.26,
+                },
+                Coord {
+                    x: 15.93,
+                    y: -15.76,
+                },
+                Coord { x: 15.93, y: 0.001 },
+                Coord { x: -0.5, y: 0.001 },
+            ],
+            vec![Coord { x: 1e-7, y: 3.0 }, Coord { x: -12.0, y: -0.75 }],
+        ];
+        let parsed = parse(&compact(&subpaths, false));
+        assert_eq!(parsed.len(), subpaths.len());
+        for (parsed, original) in parsed.iter().zip(&subpaths) {
+            assert_eq!(parsed.len(), original.len());
+            for (a, b) in parsed.iter().zip(original) {
+                assert!((a.x - b.x).abs() < 1e-9 && (a.y - b.y).abs() < 1e-9);
+            }
+        }
+    }
+}
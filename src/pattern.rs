@@ -0,0 +1,231 @@
+use crate::{Color, Svg, Unit, defs};
+use std::fmt::Write;
+
+/// A repeating fill, useful to tell polygons apart without relying on color, e.g. on printed
+/// black-and-white maps.
+///
+/// Patterns are used through [`Style::fill`](crate::Style::fill) and are written once to the
+/// `<defs>` of the enclosing SVG.
+///
+/// Example:
+/// ```
+/// use geo_types::{LineString, Polygon};
+/// use geo_svg::{Color, Pattern, ToSvg};
+///
+/// let polygon = Polygon::new(
+///     LineString::from(vec![(0.0, 0.0), (100.0, 0.0), (100.0, 100.0), (0.0, 100.0)]),
+///     vec![],
+/// );
+/// let svg = polygon
+///     .to_svg()
+///     .with_fill_pattern(
+///         Pattern::diagonal_lines()
+///             .with_spacing(6.0)
+///             .with_angle(-45.0)
+///             .with_color(Color::Named("gray")),
+///     )
+///     .with_stroke_color(Color::Named("black"));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pattern<'a> {
+    kind: PatternKind,
+    spacing: f32,
+    angle: f32,
+    color: Color<'a>,
+    stroke_width: f32,
+    background: Option<Color<'a>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum PatternKind {
+    Lines,
+    CrossHatch,
+    Dots,
+    Tile {
+        content: String,
+        width: f32,
+        height: f32,
+    },
+}
+
+impl<'a> Pattern<'a> {
+    fn new(kind: PatternKind, angle: f32) -> Self {
+        Self {
+            kind,
+            spacing: 8.0,
+            angle,
+            color: Color::Named("black"),
+            stroke_width: 1.0,
+            background: None,
+        }
+    }
+
+    /// Parallel lines, slanted by 45 degrees unless another angle is set.
+    pub fn diagonal_lines() -> Self {
+        Self::new(PatternKind::Lines, 45.0)
+    }
+
+    /// Two sets of perpendicular lines, slanted by 45 degrees unless another angle is set.
+    pub fn cross_hatch() -> Self {
+        Self::new(PatternKind::CrossHatch, 45.0)
+    }
+
+    /// Dots laid out on a square grid.
+    pub fn dots() -> Self {
+        Self::new(PatternKind::Dots, 0.0)
+    }
+
+    /// Repeats the content of another SVG, scaled to fit a `width` x `height` tile.
+    ///
+    /// The spacing, color and stroke width of the pattern do not apply to tiles.
+    pub fn tile(svg: &Svg, width: f32, height: f32) -> Self {
+        let mut tile = svg.clone();
+        tile.set_width(Unit::None(width));
+        tile.set_height(Unit::None(height));
+        Self::new(
+            PatternKind::Tile {
+                content: tile.to_string(),
+                width,
+                height,
+            },
+            0.0,
+        )
+    }
+
+    /// distance between two lines or dots
+    pub fn with_spacing(self, spacing: f32) -> Self {
+        Self { spacing, ..self }
+    }
+
+    /// rotation of the pattern in degrees, clockwise
+    pub fn with_angle(self, angle: f32) -> Self {
+        Self { angle, ..self }
+    }
+
+    /// color of the lines or dots
+    pub fn with_color(self, color: Color<'a>) -> Self {
+        Self { color, ..self }
+    }
+
+    /// width of the lines, or radius of the dots
+    pub fn with_stroke_width(self, stroke_width: f32) -> Self {
+        Self {
+            stroke_width,
+            ..self
+        }
+    }
+
+    /// color painted behind the lines or dots, transparent by default
+    pub fn with_background(self, background: Color<'a>) -> Self {
+        Self {
+            background: Some(background),
+            ..self
+        }
+    }
+
+    /// Element id under which the pattern is registered in `<defs>`.
+    pub fn id(&self) -> String {
+        defs::id("pattern", self)
+    }
+
+    /// The `<pattern>` element defining this pattern.
+    pub fn to_svg_def(&self) -> String {
+        let (width, height) = match &self.kind {
+            PatternKind::Tile { width, height, .. } => (*width, *height),
+            _ => (self.spacing, self.spacing),
+        };
+        let mut def = format!(
+            r#"<pattern id="{id}" patternUnits="userSpaceOnUse" width="{width}" height="{height}""#,
+            id = self.id(),
+        );
+        if self.angle != 0.0 {
+            write!(def, r#" patternTransform="rotate({})""#, self.angle).unwrap();
+        }
+        def.push('>');
+        if let Some(background) = self.background {
+            write!(
+                def,
+                r#"<rect width="{width}" height="{height}" fill="{background}"/>"#
+            )
+            .unwrap();
+        }
+        let (spacing, half, color, stroke_width) = (
+            self.spacing,
+            self.spacing / 2.0,
+            self.color,
+            self.stroke_width,
+        );
+        match &self.kind {
+            PatternKind::Lines => write!(
+                def,
+                r#"<path d="M 0 {half} L {spacing} {half}" stroke="{color}" stroke-width="{stroke_width}"/>"#
+            ),
+            PatternKind::CrossHatch => write!(
+                def,
+                r#"<path d="M 0 {half} L {spacing} {half} M {half} 0 L {half} {spacing}" stroke="{color}" stroke-width="{stroke_width}"/>"#
+            ),
+            PatternKind::Dots => write!(
+                def,
+                r#"<circle cx="{half}" cy="{half}" r="{stroke_width}" fill="{color}"/>"#
+            ),
+            PatternKind::Tile { content, .. } => write!(def, "{content}"),
+        }
+        .unwrap();
+        def.push_str("</pattern>");
+        def
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ToSvg;
+    use geo_types::{Point, Rect};
+
+    #[test]
+    fn test_diagonal_lines() {
+        let pattern = Pattern::diagonal_lines()
+            .with_spacing(4.0)
+            .with_color(Color::Named("gray"));
+        assert_eq!(
+            pattern.to_svg_def(),
+            format!(
+                r#"<pattern id="{}" patternUnits="userSpaceOnUse" width="4" height="4" patternTransform="rotate(45)"><path d="M 0 2 L 4 2" stroke="gray" stroke-width="1"/></pattern>"#,
+                pattern.id()
+            )
+        );
+    }
+
+    #[test]
+    fn test_id_depends_on_definition() {
+        assert_eq!(Pattern::dots().id(), Pattern::dots().id());
+        assert_ne!(Pattern::dots().id(), Pattern::dots().with_spacing(2.0).id());
+    }
+
+    #[test]
+    fn test_tile() {
+        let point = Point::new(0.0, 0.0);
+        let pattern = Pattern::tile(&point.to_svg(), 5.0, 5.0);
+        let def = pattern.to_svg_def();
+        assert!(def.contains(r#"width="5" height="5""#));
+        assert!(def.contains("<circle"));
+    }
+
+    #[test]
+    fn test_fill_pattern_is_defined_once() {
+        let pattern = Pattern::cross_hatch();
+        let rect = Rect::new((0.0, 0.0), (10.0, 10.0));
+        let svg = rect
+            .to_svg()
+            .and(rect.to_svg())
+            .with_fill_pattern(pattern.clone())
+            .to_string();
+        assert_eq!(svg.matches("<pattern").count(), 1);
+        assert!(svg.contains(&format!("<defs>{}</defs>", pattern.to_svg_def())));
+        assert_eq!(
+            svg.matches(&format!(r#"fill="url(#{})""#, pattern.id()))
+                .count(),
+            2
+        );
+    }
+}
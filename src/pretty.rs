@@ -0,0 +1,214 @@
+/// Layout of the SVG written by [`Svg`](crate::Svg), one element per line, indented after the
+/// tree of groups, instead of a single line.
+///
+/// Example:
+/// ```
+/// use geo_types::Point;
+/// use geo_svg::{PrettyPrint, ToSvg};
+///
+/// let point = Point::new(10.0, 10.0);
+/// let svg = point
+///     .to_svg()
+///     .with_pretty_print(PrettyPrint::default().with_path_wrap(80));
+/// println!("{svg}");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PrettyPrint {
+    /// Number of spaces per level of nesting.
+    pub indent: usize,
+    /// Maximum length of the lines of path data, which is split before the commands, or `None`
+    /// to keep it on a single line.
+    pub path_wrap: Option<usize>,
+}
+
+impl Default for PrettyPrint {
+    fn default() -> Self {
+        Self {
+            indent: 2,
+            path_wrap: None,
+        }
+    }
+}
+
+impl PrettyPrint {
+    pub fn with_indent(self, indent: usize) -> Self {
+        Self { indent, ..self }
+    }
+
+    pub fn with_path_wrap(self, path_wrap: usize) -> Self {
+        Self {
+            path_wrap: Some(path_wrap),
+            ..self
+        }
+    }
+
+    /// Re-indents the single line `svg`, keeping elements which only contain text on one line.
+    pub(crate) fn format(&self, svg: &str) -> String {
+        let tokens = tokens(svg);
+        let mut lines = vec![];
+        let mut depth = 0;
+        let mut i = 0;
+        while i < tokens.len() {
+            let indent = " ".repeat(depth * self.indent);
+            match tokens[i..] {
+                [
+                    Token::Open(open),
+                    Token::Text(text),
+                    Token::Close(close),
+                    ..,
+                ] => {
+                    lines.push(format!("{indent}{open}{text}{close}"));
+                    i += 3;
+                }
+                [Token::Open(open), Token::Close(close), ..] => {
+                    lines.push(format!("{indent}{open}{close}"));
+                    i += 2;
+                }
+                [Token::Open(open), ..] => {
+                    lines.push(format!("{indent}{}", self.wrap_path(open, depth)));
+                    depth += 1;
+                    i += 1;
+                }
+                [Token::Close(close), ..] => {
+                    depth = depth.saturating_sub(1);
+                    let indent = " ".repeat(depth * self.indent);
+                    lines.push(format!("{indent}{close}"));
+                    i += 1;
+                }
+                [Token::Empty(element), ..] => {
+                    lines.push(format!("{indent}{}", self.wrap_path(element, depth)));
+                    i += 1;
+                }
+                [Token::Text(text), ..] => {
+                    if !text.trim().is_empty() {
+                        lines.push(format!("{indent}{}", text.trim()));
+                    }
+                    i += 1;
+                }
+                [] => unreachable!(),
+            }
+        }
+        lines.join("\n")
+    }
+
+    /// Splits the path data of `tag` over several lines, indented two levels deeper than `depth`.
+    fn wrap_path(&self, tag: &str, depth: usize) -> String {
+        let Some(path_wrap) = self.path_wrap else {
+            return tag.to_string();
+        };
+        let Some(start) = tag.find(r#" d=""#).map(|start| start + 4) else {
+            return tag.to_string();
+        };
+        let Some(len) = tag[start..].find('"') else {
+            return tag.to_string();
+        };
+        let data = &tag[start..start + len];
+        let mut lines: Vec<String> = vec![];
+        for command in commands(data) {
+            match lines.last_mut() {
+                Some(line) if line.len() + command.trim_end().len() <= path_wrap => {
+                    line.push_str(command)
+                }
+                _ => lines.push(command.to_string()),
+            }
+        }
+        if lines.len() < 2 {
+            return tag.to_string();
+        }
+        let separator = format!("\n{}", " ".repeat((depth + 2) * self.indent));
+        let lines: Vec<&str> = lines.iter().map(|line| line.trim()).collect();
+        format!(
+            "{}{}{}",
+            &tag[..start],
+            lines.join(&separator),
+            &tag[start + len..]
+        )
+    }
+}
+
+enum Token<'s> {
+    Open(&'s str),
+    Close(&'s str),
+    Empty(&'s str),
+    Text(&'s str),
+}
+
+fn tokens(svg: &str) -> Vec<Token<'_>> {
+    let mut tokens = vec![];
+    let mut rest = svg;
+    while !rest.is_empty() {
+        if !rest.starts_with('<') {
+            let end = rest.find('<').unwrap_or(rest.len());
+            tokens.push(Token::Text(&rest[..end]));
+            rest = &rest[end..];
+            continue;
+        }
+        let mut quoted = false;
+        let end = rest
+            .char_indices()
+            .find(|&(_, c)| {
+                if c == '"' {
+                    quoted = !quoted;
+                }
+                c == '>' && !quoted
+            })
+            .map_or(rest.len(), |(end, _)| end + 1);
+        let tag = &rest[..end];
+        tokens.push(if tag.starts_with("</") {
+            Token::Close(tag)
+        } else if tag.ends_with("/>") {
+            Token::Empty(tag)
+        } else {
+            Token::Open(tag)
+        });
+        rest = &rest[end..];
+    }
+    tokens
+}
+
+/// Splits path data before each command letter.
+fn commands(data: &str) -> Vec<&str> {
+    let mut commands = vec![];
+    let mut start = 0;
+    for (i, c) in data.char_indices() {
+        if i > start && c.is_ascii_alphabetic() && c != 'e' && c != 'E' {
+            commands.push(&data[start..i]);
+            start = i;
+        }
+    }
+    commands.push(&data[start..]);
+    commands
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format() {
+        let svg = r#"<svg viewBox="0 0 1 1"><g clip-path="url(#a)"><circle r="1"/><text x="0">a > b</text></g><g></g></svg>"#;
+        assert_eq!(
+            PrettyPrint::default().format(svg),
+            r#"<svg viewBox="0 0 1 1">
+  <g clip-path="url(#a)">
+    <circle r="1"/>
+    <text x="0">a > b</text>
+  </g>
+  <g></g>
+</svg>"#
+        );
+    }
+
+    #[test]
+    fn test_path_wrap() {
+        let svg = r#"<g><path d="M 0 0 L 10 0 L 10 10 L 0 10 Z" fill="red"/></g>"#;
+        assert_eq!(
+            PrettyPrint::default().with_path_wrap(14).format(svg),
+            r#"<g>
+  <path d="M 0 0 L 10 0
+      L 10 10 L 0 10
+      Z" fill="red"/>
+</g>"#
+        );
+    }
+}
@@ -0,0 +1,138 @@
+use crate::Style;
+use crate::cast::{from_f64, to_f64};
+use geo_types::{Coord, CoordNum};
+use std::fmt::{Debug, Formatter, Result};
+use std::sync::Arc;
+
+/// Latitude beyond which Web Mercator is undefined, making the projected world square.
+const MAX_MERCATOR_LATITUDE: f64 = 85.051_128_779_806_59;
+
+/// Maps the coordinates of geometries, typically WGS84 longitude and latitude in degrees, to the
+/// plane of the SVG.
+///
+/// The built-in projections keep the scale of the input, one degree of longitude at the equator
+/// being one SVG unit, and flip the y axis so that north is up.
+///
+/// Example:
+/// ```
+/// use geo_types::Point;
+/// use geo_svg::{Projection, ToSvg};
+///
+/// let paris = Point::new(2.35, 48.86);
+/// let svg = paris.to_svg().with_projection(Projection::WebMercator);
+/// ```
+#[derive(Clone)]
+pub enum Projection {
+    /// Conformal projection used by most web maps.
+    WebMercator,
+    /// Plate carrée with longitudes scaled by the cosine of `standard_parallel`, which keeps
+    /// shapes undistorted around that latitude.
+    Equirectangular { standard_parallel: f64 },
+    /// Any other mapping, applied as is.
+    Custom(Arc<dyn Fn(Coord<f64>) -> Coord<f64> + Send + Sync>),
+}
+
+impl Projection {
+    pub fn custom(projection: impl Fn(Coord<f64>) -> Coord<f64> + Send + Sync + 'static) -> Self {
+        Self::Custom(Arc::new(projection))
+    }
+
+    pub fn project<T: CoordNum>(&self, coord: Coord<T>) -> Coord<T> {
+        let Coord { x, y } = to_f64(coord);
+        let projected = match self {
+            Self::WebMercator => {
+                let latitude = y
+                    .clamp(-MAX_MERCATOR_LATITUDE, MAX_MERCATOR_LATITUDE)
+                    .to_radians();
+                let y = (std::f64::consts::FRAC_PI_4 + latitude / 2.0)
+                    .tan()
+                    .ln()
+                    .to_degrees();
+                Coord { x, y: -y }
+            }
+            Self::Equirectangular { standard_parallel } => Coord {
+                x: x * standard_parallel.to_radians().cos(),
+                y: -y,
+            },
+            Self::Custom(projection) => projection(Coord { x, y }),
+        };
+        from_f64(projected)
+    }
+}
+
+impl Debug for Projection {
+    fn fmt(&self, fmt: &mut Formatter) -> Result {
+        match self {
+            Self::WebMercator => write!(fmt, "WebMercator"),
+            Self::Equirectangular { standard_parallel } => fmt
+                .debug_struct("Equirectangular")
+                .field("standard_parallel", standard_parallel)
+                .finish(),
+            Self::Custom(_) => write!(fmt, "Custom"),
+        }
+    }
+}
+
+impl PartialEq for Projection {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::WebMercator, Self::WebMercator) => true,
+            (
+                Self::Equirectangular { standard_parallel },
+                Self::Equirectangular {
+                    standard_parallel: other,
+                },
+            ) => standard_parallel == other,
+            (Self::Custom(projection), Self::Custom(other)) => Arc::ptr_eq(projection, other),
+            _ => false,
+        }
+    }
+}
+
+/// Projects `coord` with the projection of `style`, if any.
+pub(crate) fn project<T: CoordNum>(style: &Style, coord: Coord<T>) -> Coord<T> {
+    match &style.projection {
+        Some(projection) => projection.project(coord),
+        None => coord,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_web_mercator() {
+        let projection = Projection::WebMercator;
+        let equator = projection.project(Coord { x: 10.0, y: 0.0f64 });
+        assert_eq!(equator.x, 10.0);
+        assert!(equator.y.abs() < 1e-9);
+        let north = projection.project(Coord { x: 0.0, y: 60.0f64 });
+        assert!((north.y + 75.456).abs() < 1e-3);
+        let pole = projection.project(Coord { x: 0.0, y: 90.0f64 });
+        assert!((pole.y + 180.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_equirectangular() {
+        let projection = Projection::Equirectangular {
+            standard_parallel: 60.0,
+        };
+        let projected = projection.project(Coord {
+            x: 10.0,
+            y: 60.0f64,
+        });
+        assert!((projected.x - 5.0).abs() < 1e-9);
+        assert_eq!(projected.y, -60.0);
+    }
+
+    #[test]
+    fn test_custom() {
+        let projection = Projection::custom(|Coord { x, y }| Coord { x: x * 2.0, y });
+        assert_eq!(
+            projection.project(Coord { x: 1, y: 2 }),
+            Coord { x: 2, y: 2 }
+        );
+        assert_eq!(projection, projection.clone());
+    }
+}
@@ -0,0 +1,137 @@
+//! Serialization of colors, paints and units in their CSS form, e.g. `"#FF0000"` or `"10px"`.
+
+use crate::{Color, Paint, Unit};
+use serde::de::Error as _;
+use serde::ser::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::borrow::Cow;
+
+impl Serialize for Color<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+// named colors are resolved to the static table of CSS colors, so colors can be deserialized
+// from owned strings whatever their lifetime
+impl<'de> Deserialize<'de> for Color<'_> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let color = Cow::<str>::deserialize(deserializer)?;
+        color.parse::<Color>().map_err(D::Error::custom)
+    }
+}
+
+impl Serialize for Paint<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Paint::Color(color) => color.serialize(serializer),
+            Paint::Pattern(_) => Err(S::Error::custom("fill patterns cannot be serialized")),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Paint<'_> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Color::deserialize(deserializer).map(Paint::Color)
+    }
+}
+
+impl Serialize for Unit {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Unit {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let unit = Cow::<str>::deserialize(deserializer)?;
+        parse_unit(&unit).ok_or_else(|| D::Error::custom(format!("invalid length `{unit}`")))
+    }
+}
+
+fn parse_unit(unit: &str) -> Option<Unit> {
+    let unit = unit.trim();
+    let split = unit
+        .rfind(|c: char| c.is_ascii_digit() || c == '.')
+        .map_or(0, |index| index + 1);
+    let value: f32 = unit[..split].parse().ok()?;
+    Some(match &unit[split..] {
+        "cm" => Unit::Centimeter(value),
+        "in" => Unit::Inch(value),
+        "" => Unit::None(value),
+        "mm" => Unit::Millimeter(value),
+        "pc" => Unit::Pica(value),
+        "px" => Unit::Pixel(value),
+        "pt" => Unit::Point(value),
+        "Q" => Unit::QuarterMillimeter(value),
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FillRule, LineCap, Pattern, Style, ViewBox};
+
+    #[test]
+    fn test_color() {
+        let colors: Vec<Color> =
+            serde_json::from_str(r##"["Red", "#00ff0080", "hsl(120, 50%, 25%)"]"##).unwrap();
+        assert_eq!(
+            colors,
+            vec![
+                Color::RED,
+                Color::Rgba(0, 255, 0, 128.0 / 255.0),
+                Color::Hsl(120, 50, 25)
+            ]
+        );
+        assert_eq!(
+            serde_json::to_string(&colors).unwrap(),
+            r#"["red","rgba(0,255,0,0.5019608)","hsl(120,50%,25%)"]"#
+        );
+        assert!(serde_json::from_str::<Color>(r#""lightgrey2""#).is_err());
+    }
+
+    #[test]
+    fn test_unit() {
+        let units: Vec<Unit> = serde_json::from_str(r#"["10px", "2.5cm", "3", "1Q"]"#).unwrap();
+        assert_eq!(
+            serde_json::to_string(&units).unwrap(),
+            r#"["10px","2.5cm","3","1Q"]"#
+        );
+        assert!(serde_json::from_str::<Unit>(r#""10furlongs""#).is_err());
+    }
+
+    #[test]
+    fn test_style() {
+        let style: Style = serde_json::from_str(
+            r#"{"fill": "steelblue", "stroke-width": 2, "stroke-linecap": "round", "fill-rule": "nonzero"}"#,
+        )
+        .unwrap();
+        assert_eq!(style.fill, Some(Color::STEEL_BLUE.into()));
+        assert_eq!(style.stroke_width, Some(2.0));
+        assert_eq!(style.stroke_linecap, Some(LineCap::Round));
+        assert_eq!(style.fill_rule, Some(FillRule::NonZero));
+        assert_eq!(style.radius, None);
+        assert_eq!(
+            serde_json::to_string(&style).unwrap(),
+            r#"{"fill":"steelblue","stroke-width":2.0,"stroke-linecap":"round","fill-rule":"nonzero"}"#
+        );
+        let patterned = Style {
+            fill: Some(Pattern::dots().into()),
+            ..Style::default()
+        };
+        assert!(serde_json::to_string(&patterned).is_err());
+    }
+
+    #[test]
+    fn test_viewbox() {
+        let viewbox: ViewBox =
+            serde_json::from_str(r#"{"min_x": 0, "min_y": 0, "max_x": 10, "max_y": 5}"#).unwrap();
+        assert_eq!(viewbox.width(), 10.0);
+        assert_eq!(
+            serde_json::from_str::<ViewBox>(&serde_json::to_string(&viewbox).unwrap()).unwrap(),
+            viewbox
+        );
+    }
+}
@@ -0,0 +1,258 @@
+use crate::Style;
+use crate::cast::to_f64;
+use geo_types::{Coord, CoordNum};
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// Algorithm used to drop the vertices of lines and polygons which cannot be seen at the
+/// resolution of the output.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum SimplifyAlgorithm {
+    /// Keeps the vertices farther than the tolerance from the simplified line.
+    #[default]
+    DouglasPeucker,
+    /// Drops the vertices forming the triangles of smallest area with their neighbours, as long
+    /// as that area is below the square of the tolerance, which tends to give smoother outlines.
+    Visvalingam,
+}
+
+/// Render time simplification of lines and polygons.
+///
+/// Line strings keep at least two vertices and rings at least three distinct vertices, so
+/// simplified geometries stay valid.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Simplification {
+    pub algorithm: SimplifyAlgorithm,
+    /// How far, in pixels, the simplified outline may deviate from the original one.
+    pub tolerance: f32,
+    /// Size of a pixel in SVG units, computed from the view box and the image size when the
+    /// SVG is rendered.
+    pub pixel_size: f32,
+}
+
+impl Simplification {
+    pub fn new(algorithm: SimplifyAlgorithm, tolerance: f32) -> Self {
+        Self {
+            algorithm,
+            tolerance,
+            pixel_size: 1.0,
+        }
+    }
+
+    /// Simplifies a line string, or a ring if `closed`.
+    pub fn simplify<T: CoordNum>(&self, coords: Vec<Coord<T>>, closed: bool) -> Vec<Coord<T>> {
+        let min_len = if closed { 4 } else { 2 };
+        if coords.len() <= min_len {
+            return coords;
+        }
+        let tolerance = f64::from(self.tolerance * self.pixel_size);
+        let points: Vec<Coord<f64>> = coords.iter().copied().map(to_f64).collect();
+        let mut keep = match self.algorithm {
+            SimplifyAlgorithm::DouglasPeucker => douglas_peucker(&points, tolerance),
+            SimplifyAlgorithm::Visvalingam => visvalingam(&points, tolerance, min_len),
+        };
+        keep_at_least(&points, &mut keep, min_len);
+        coords
+            .into_iter()
+            .zip(keep)
+            .filter_map(|(coord, keep)| keep.then_some(coord))
+            .collect()
+    }
+}
+
+/// Simplifies `coords` with the simplification of `style`, if any.
+pub(crate) fn simplify<T: CoordNum>(
+    style: &Style,
+    coords: Vec<Coord<T>>,
+    closed: bool,
+) -> Vec<Coord<T>> {
+    match &style.simplification {
+        Some(simplification) => simplification.simplify(coords, closed),
+        None => coords,
+    }
+}
+
+fn douglas_peucker(points: &[Coord<f64>], tolerance: f64) -> Vec<bool> {
+    let last = points.len() - 1;
+    let mut keep = vec![false; points.len()];
+    keep[0] = true;
+    keep[last] = true;
+    let mut stack = vec![(0, last)];
+    while let Some((start, end)) = stack.pop() {
+        let farthest = (start + 1..end)
+            .map(|i| (i, segment_distance(points[i], points[start], points[end])))
+            .max_by(|(_, a), (_, b)| a.total_cmp(b));
+        if let Some((index, distance)) = farthest
+            && distance > tolerance
+        {
+            keep[index] = true;
+            stack.push((start, index));
+            stack.push((index, end));
+        }
+    }
+    keep
+}
+
+/// Keeps the vertices farthest from the simplified outline until `min_len` of them are kept, e.g.
+/// when a ring is smaller than the tolerance.
+fn keep_at_least(points: &[Coord<f64>], keep: &mut [bool], min_len: usize) {
+    while keep.iter().filter(|keep| **keep).count() < min_len {
+        let kept: Vec<usize> = (0..keep.len()).filter(|&i| keep[i]).collect();
+        let farthest = kept
+            .windows(2)
+            .flat_map(|pair| {
+                (pair[0] + 1..pair[1]).map(|i| {
+                    (
+                        i,
+                        segment_distance(points[i], points[pair[0]], points[pair[1]]),
+                    )
+                })
+            })
+            .max_by(|(_, a), (_, b)| a.total_cmp(b));
+        match farthest {
+            Some((index, _)) => keep[index] = true,
+            None => break,
+        }
+    }
+}
+
+fn segment_distance(point: Coord<f64>, start: Coord<f64>, end: Coord<f64>) -> f64 {
+    let segment = end - start;
+    let length_squared = segment.x * segment.x + segment.y * segment.y;
+    let closest = if length_squared == 0.0 {
+        start
+    } else {
+        let offset = point - start;
+        let t = ((offset.x * segment.x + offset.y * segment.y) / length_squared).clamp(0.0, 1.0);
+        start + segment * t
+    };
+    (point.x - closest.x).hypot(point.y - closest.y)
+}
+
+fn visvalingam(points: &[Coord<f64>], tolerance: f64, min_len: usize) -> Vec<bool> {
+    let len = points.len();
+    let mut keep = vec![true; len];
+    let mut previous: Vec<usize> = (0..len).map(|i| i.saturating_sub(1)).collect();
+    let mut next: Vec<usize> = (1..=len).collect();
+    let area = |a: usize, b: usize, c: usize| {
+        let (a, b, c) = (points[a], points[b], points[c]);
+        ((b.x - a.x) * (c.y - a.y) - (c.x - a.x) * (b.y - a.y)).abs() / 2.0
+    };
+    // areas are never negative, so their bit patterns sort like the areas themselves
+    let mut areas: Vec<u64> = vec![0; len];
+    let mut heap = BinaryHeap::new();
+    for (i, bits) in areas.iter_mut().enumerate().take(len - 1).skip(1) {
+        *bits = area(i - 1, i, i + 1).to_bits();
+        heap.push(Reverse((*bits, i)));
+    }
+    let threshold = tolerance * tolerance;
+    let mut remaining = len;
+    while let Some(Reverse((bits, i))) = heap.pop() {
+        if !keep[i] || areas[i] != bits {
+            continue;
+        }
+        let removed_area = f64::from_bits(bits);
+        if removed_area >= threshold || remaining <= min_len {
+            break;
+        }
+        keep[i] = false;
+        remaining -= 1;
+        let (before, after) = (previous[i], next[i]);
+        next[before] = after;
+        previous[after] = before;
+        for neighbour in [before, after] {
+            if neighbour != 0 && neighbour != len - 1 {
+                let neighbour_area =
+                    area(previous[neighbour], neighbour, next[neighbour]).max(removed_area);
+                areas[neighbour] = neighbour_area.to_bits();
+                heap.push(Reverse((areas[neighbour], neighbour)));
+            }
+        }
+    }
+    keep
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn zigzag() -> Vec<Coord<f64>> {
+        (0..=10)
+            .map(|i| Coord {
+                x: f64::from(i),
+                y: if i % 2 == 0 { 0.0 } else { 0.1 },
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_douglas_peucker() {
+        let simplification = Simplification::new(SimplifyAlgorithm::DouglasPeucker, 0.5);
+        assert_eq!(
+            simplification.simplify(zigzag(), false),
+            vec![Coord { x: 0.0, y: 0.0 }, Coord { x: 10.0, y: 0.0 }]
+        );
+        let fine = Simplification::new(SimplifyAlgorithm::DouglasPeucker, 0.05);
+        assert_eq!(fine.simplify(zigzag(), false), zigzag());
+    }
+
+    #[test]
+    fn test_visvalingam() {
+        let simplification = Simplification::new(SimplifyAlgorithm::Visvalingam, 1.0);
+        assert_eq!(
+            simplification.simplify(zigzag(), false),
+            vec![Coord { x: 0.0, y: 0.0 }, Coord { x: 10.0, y: 0.0 }]
+        );
+    }
+
+    #[test]
+    fn test_pixel_size() {
+        let simplification = Simplification {
+            pixel_size: 0.01,
+            ..Simplification::new(SimplifyAlgorithm::DouglasPeucker, 0.5)
+        };
+        assert_eq!(simplification.simplify(zigzag(), false), zigzag());
+    }
+
+    #[test]
+    fn test_rings_stay_valid() {
+        let ring = vec![
+            Coord { x: 0.0, y: 0.0 },
+            Coord { x: 0.1, y: 0.0 },
+            Coord { x: 0.1, y: 0.1 },
+            Coord { x: 0.05, y: 0.15 },
+            Coord { x: 0.0, y: 0.1 },
+            Coord { x: 0.0, y: 0.0 },
+        ];
+        for algorithm in [
+            SimplifyAlgorithm::DouglasPeucker,
+            SimplifyAlgorithm::Visvalingam,
+        ] {
+            let simplified = Simplification::new(algorithm, 10.0).simplify(ring.clone(), true);
+            assert!(simplified.len() >= 4, "{algorithm:?} collapsed the ring");
+            assert_eq!(simplified.first(), simplified.last());
+        }
+    }
+
+    #[test]
+    fn test_dense_sub_pixel_ring() {
+        let mut ring: Vec<Coord<f64>> = (0..10_000)
+            .map(|i| {
+                let angle = f64::from(i) / 10_000.0 * std::f64::consts::TAU;
+                Coord {
+                    x: 0.1 * angle.cos(),
+                    y: 0.1 * angle.sin(),
+                }
+            })
+            .collect();
+        ring.push(ring[0]);
+        for algorithm in [
+            SimplifyAlgorithm::DouglasPeucker,
+            SimplifyAlgorithm::Visvalingam,
+        ] {
+            let simplified = Simplification::new(algorithm, 1.0).simplify(ring.clone(), true);
+            assert_eq!(simplified.len(), 4, "{algorithm:?}");
+            assert_eq!(simplified.first(), simplified.last());
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::Color;
+use crate::{Color, Paint, PathEncoding, Projection, Shapes, Simplification, ViewBox};
 use std::fmt::{Display, Formatter, Result};
 
 /// LineCap is used to define the shape to be used at the end of strokes.
@@ -34,6 +34,8 @@ use std::fmt::{Display, Formatter, Result};
 #[doc = include_str!("../images/linecap_square.svg")]
 ///
 #[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
 pub enum LineCap {
     #[default]
     Butt,
@@ -77,6 +79,8 @@ pub enum LineCap {
 #[doc = include_str!("../images/linejoin_bevel.svg")]
 ///
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
 pub enum LineJoin {
     #[default]
     Miter,
@@ -84,35 +88,123 @@ pub enum LineJoin {
     Bevel,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+/// FillRule is used to define which parts of a polygon are inside of it, where its rings overlap
+/// or nest.
+///
+/// Holes are only left out with the non-zero rule if they wind the other way around than their
+/// exterior, which `geo_types` does not guarantee.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum FillRule {
+    NonZero,
+    #[default]
+    EvenOdd,
+}
+
+impl Display for FillRule {
+    fn fmt(&self, fmt: &mut Formatter) -> Result {
+        match self {
+            FillRule::NonZero => write!(fmt, "nonzero"),
+            FillRule::EvenOdd => write!(fmt, "evenodd"),
+        }
+    }
+}
+
+/// Presentation attributes of the rendered geometries.
+///
+/// With the `serde` feature, styles are (de)serialized with kebab-case field names and colors in
+/// their CSS form, such as `{"fill": "steelblue", "stroke-width": 2}`, leaving out the render
+/// options from `viewport` on.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, rename_all = "kebab-case"))]
 pub struct Style<'a> {
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub opacity: Option<f32>,
-    pub fill: Option<Color<'a>>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub fill: Option<Paint<'a>>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub fill_opacity: Option<f32>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub stroke_color: Option<Color<'a>>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub stroke_width: Option<f32>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub stroke_opacity: Option<f32>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub stroke_dasharray: Option<Vec<f32>>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub stroke_linecap: Option<LineCap>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub stroke_linejoin: Option<LineJoin>,
-    pub radius: f32,
+    /// Fill rule of polygons, even-odd by default and non-zero for multi-polygons written as a
+    /// single path. Written on polygons only.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub fill_rule: Option<FillRule>,
+    /// Radius of the circles of points, see [`Style::radius`].
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub radius: Option<f32>,
+    /// Geometries are clipped to this area, and left out when they lie entirely outside of it.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub viewport: Option<ViewBox>,
+    /// Applied to every coordinate before it is written, and before clipping to the viewport.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub projection: Option<Projection>,
+    /// Drops the vertices of lines and polygons which cannot be seen in the output.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub simplification: Option<Simplification>,
+    /// How the `d` attribute of paths is written, absolute commands by default.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub path_encoding: Option<PathEncoding>,
+    /// Which elements geometries are written as, paths by default.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub shapes: Option<Shapes>,
+    /// Horizontal and vertical radii of the corners of rectangles written as `<rect>` elements.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub corner_radius: Option<(f32, f32)>,
+    /// Writes the polygons of a multi-polygon as a single path, filled with the non-zero rule
+    /// unless `fill_rule` is set, so that their overlaps are filled once.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub single_path: Option<bool>,
 }
 
-impl Default for Style<'_> {
-    fn default() -> Self {
+impl<'a> Style<'a> {
+    /// Fills the fields which are not set with those of `parent`.
+    pub fn inherit(self, parent: &Style<'a>) -> Self {
         Self {
-            opacity: None,
-            fill: None,
-            fill_opacity: None,
-            stroke_color: None,
-            stroke_width: None,
-            stroke_opacity: None,
-            stroke_dasharray: None,
-            stroke_linecap: None,
-            stroke_linejoin: None,
-            radius: 1.0,
+            opacity: self.opacity.or(parent.opacity),
+            fill: self.fill.or_else(|| parent.fill.clone()),
+            fill_opacity: self.fill_opacity.or(parent.fill_opacity),
+            stroke_color: self.stroke_color.or(parent.stroke_color),
+            stroke_width: self.stroke_width.or(parent.stroke_width),
+            stroke_opacity: self.stroke_opacity.or(parent.stroke_opacity),
+            stroke_dasharray: self
+                .stroke_dasharray
+                .or_else(|| parent.stroke_dasharray.clone()),
+            stroke_linecap: self.stroke_linecap.or(parent.stroke_linecap),
+            stroke_linejoin: self.stroke_linejoin.or(parent.stroke_linejoin),
+            fill_rule: self.fill_rule.or(parent.fill_rule),
+            radius: self.radius.or(parent.radius),
+            viewport: self.viewport.or(parent.viewport),
+            projection: self.projection.or_else(|| parent.projection.clone()),
+            simplification: self.simplification.or(parent.simplification),
+            path_encoding: self.path_encoding.or(parent.path_encoding),
+            shapes: self.shapes.or(parent.shapes),
+            corner_radius: self.corner_radius.or(parent.corner_radius),
+            single_path: self.single_path.or(parent.single_path),
         }
     }
+
+    /// Radius of the circles of points, `1.0` unless set.
+    pub fn radius(&self) -> f32 {
+        self.radius.unwrap_or(1.0)
+    }
+
+    /// Definitions the style refers to, such as fill patterns, to be written in `<defs>`.
+    pub fn defs(&self) -> Vec<String> {
+        self.fill.iter().flat_map(Paint::defs).collect()
+    }
 }
 
 impl Display for Style<'_> {
@@ -120,7 +212,7 @@ impl Display for Style<'_> {
         if let Some(opacity) = self.opacity {
             write!(fmt, r#" opacity="{opacity}""#)?;
         }
-        if let Some(fill) = self.fill {
+        if let Some(fill) = &self.fill {
             write!(fmt, r#" fill="{fill}""#)?;
         }
         if let Some(fill_opacity) = self.fill_opacity {
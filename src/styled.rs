@@ -0,0 +1,234 @@
+use crate::{Colormap, Normalization, Style, ToSvgStr, ViewBox};
+use geo_types::{
+    CoordNum, Geometry, GeometryCollection, LineString, MultiLineString, MultiPoint, MultiPolygon,
+    Point, Polygon,
+};
+
+/// Members of a collection rendered each with its own style, see [`StyleEach`].
+pub struct StyledItems<'s, T, F> {
+    items: &'s [T],
+    style: F,
+}
+
+/// Renders the members of a collection with the style returned for each of them by a closure,
+/// given its index and the member itself.
+///
+/// The fields left unset in the returned style are inherited from the style of the
+/// [`Svg`](crate::Svg), see [`Style::inherit`].
+///
+/// Example:
+/// ```
+/// use geo_types::{MultiPolygon, Rect};
+/// use geo_svg::{Color, Style, StyleEach, ToSvg};
+///
+/// let parts = MultiPolygon::new(vec![
+///     Rect::new((0.0, 0.0), (1.0, 1.0)).to_polygon(),
+///     Rect::new((2.0, 0.0), (4.0, 2.0)).to_polygon(),
+/// ]);
+/// let styled = parts.style_each(|index, _| Style {
+///     fill: Some(if index == 0 { Color::Named("red") } else { Color::Named("blue") }.into()),
+///     ..Style::default()
+/// });
+/// let svg = styled.to_svg().with_stroke_width(0.1);
+/// ```
+pub trait StyleEach<T> {
+    fn style_each<'a, F>(&self, style: F) -> StyledItems<'_, T, F>
+    where
+        F: Fn(usize, &T) -> Style<'a>;
+
+    /// Colors each member with the fill and stroke colors of `colormap` for the value returned
+    /// by `value`, normalized relative to the values of the other members.
+    ///
+    /// Members whose value cannot be normalized, such as NaN, keep the colors of the
+    /// [`Svg`](crate::Svg).
+    ///
+    /// Example:
+    /// ```
+    /// use geo_types::Point;
+    /// use geo_svg::{Colormap, Normalization, StyleEach, ToSvg};
+    ///
+    /// let stations = vec![Point::new(0.0, 0.0), Point::new(1.0, 1.0)];
+    /// let elevations = [120.0, 2450.0];
+    /// let colored = stations.colormap_each(Colormap::Viridis, Normalization::Linear, |station| {
+    ///     elevations[station.x() as usize]
+    /// });
+    /// let svg = colored.to_svg().with_radius(0.2);
+    /// ```
+    fn colormap_each(
+        &self,
+        colormap: Colormap,
+        normalization: Normalization,
+        value: impl Fn(&T) -> f64,
+    ) -> StyledItems<'_, T, impl Fn(usize, &T) -> Style<'static>>;
+}
+
+impl<T> StyleEach<T> for [T] {
+    fn style_each<'a, F>(&self, style: F) -> StyledItems<'_, T, F>
+    where
+        F: Fn(usize, &T) -> Style<'a>,
+    {
+        StyledItems { items: self, style }
+    }
+
+    fn colormap_each(
+        &self,
+        colormap: Colormap,
+        normalization: Normalization,
+        value: impl Fn(&T) -> f64,
+    ) -> StyledItems<'_, T, impl Fn(usize, &T) -> Style<'static>> {
+        let values: Vec<f64> = self.iter().map(value).collect();
+        let colors: Vec<_> = normalization
+            .normalize(&values)
+            .into_iter()
+            .map(|position| position.map(|position| colormap.color(position)))
+            .collect();
+        self.style_each(move |index, _| match colors[index] {
+            Some(color) => Style {
+                fill: Some(color.into()),
+                stroke_color: Some(color),
+                ..Style::default()
+            },
+            None => Style::default(),
+        })
+    }
+}
+
+macro_rules! impl_style_each {
+    ($collection:ident, $item:ident) => {
+        impl<T: CoordNum> StyleEach<$item<T>> for $collection<T> {
+            fn style_each<'a, F>(&self, style: F) -> StyledItems<'_, $item<T>, F>
+            where
+                F: Fn(usize, &$item<T>) -> Style<'a>,
+            {
+                self.0.style_each(style)
+            }
+
+            fn colormap_each(
+                &self,
+                colormap: Colormap,
+                normalization: Normalization,
+                value: impl Fn(&$item<T>) -> f64,
+            ) -> StyledItems<'_, $item<T>, impl Fn(usize, &$item<T>) -> Style<'static>> {
+                self.0.colormap_each(colormap, normalization, value)
+            }
+        }
+    };
+}
+
+impl_style_each!(MultiPoint, Point);
+impl_style_each!(MultiLineString, LineString);
+impl_style_each!(MultiPolygon, Polygon);
+impl_style_each!(GeometryCollection, Geometry);
+
+impl<'a, T, F> StyledItems<'_, T, F>
+where
+    F: Fn(usize, &T) -> Style<'a>,
+{
+    /// Members along with their style inheriting from `parent`.
+    fn styled<'p>(&self, parent: &Style<'p>) -> impl Iterator<Item = (&T, Style<'p>)>
+    where
+        'a: 'p,
+    {
+        self.items
+            .iter()
+            .enumerate()
+            .map(move |(index, item)| (item, (self.style)(index, item).inherit(parent)))
+    }
+}
+
+impl<'a, T, F> ToSvgStr for StyledItems<'_, T, F>
+where
+    T: ToSvgStr,
+    F: Fn(usize, &T) -> Style<'a>,
+{
+    fn to_svg_str(&self, style: &Style) -> String {
+        self.styled(style)
+            .map(|(item, style)| item.to_svg_str(&style))
+            .collect()
+    }
+
+    fn viewbox(&self, style: &Style) -> ViewBox {
+        self.styled(style)
+            .fold(ViewBox::default(), |view_box, (item, style)| {
+                view_box.add(&item.viewbox(&style))
+            })
+    }
+
+    fn defs(&self, style: &Style) -> Vec<String> {
+        self.styled(style)
+            .flat_map(|(item, style)| item.defs(&style))
+            .collect()
+    }
+
+    fn non_finite_coords(&self, style: &Style) -> usize {
+        self.styled(style)
+            .map(|(item, style)| item.non_finite_coords(&style))
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Color, Pattern, ToSvg};
+    use geo_types::{MultiPoint, Rect};
+
+    #[test]
+    fn test_style_each() {
+        let points = MultiPoint::from(vec![(0.0, 0.0), (10.0, 0.0)]);
+        let styled = points.style_each(|index, point| Style {
+            radius: (index == 1).then_some(point.x() as f32 / 2.0),
+            fill: Some(Color::Named("red").into()),
+            ..Style::default()
+        });
+        let svg = styled
+            .to_svg()
+            .with_radius(2.0)
+            .with_stroke_color(Color::Named("black"));
+        assert_eq!(
+            svg.svg_str(),
+            r#"<circle cx="0.0" cy="0.0" r="2" fill="red" stroke="black"/><circle cx="10.0" cy="0.0" r="5" fill="red" stroke="black"/>"#
+        );
+        assert_eq!(svg.viewbox().max_x(), 16.0);
+
+        // the default radius can be set over another one
+        let forced = points.style_each(|_, _| Style {
+            radius: Some(1.0),
+            ..Style::default()
+        });
+        let svg = forced.to_svg().with_radius(5.0);
+        assert!(
+            svg.svg_str()
+                .starts_with(r#"<circle cx="0.0" cy="0.0" r="1"/>"#)
+        );
+    }
+
+    #[test]
+    fn test_colormap_each() {
+        let points = MultiPoint::from(vec![(0.0, 0.0), (1.0, 0.0), (2.0, 0.0)]);
+        let colored = points.colormap_each(Colormap::Viridis, Normalization::Linear, |point| {
+            if point.x() == 1.0 {
+                f64::NAN
+            } else {
+                point.x()
+            }
+        });
+        let svg = colored.to_svg().with_color(Color::Named("red")).svg_str();
+        assert_eq!(
+            svg,
+            r#"<circle cx="0.0" cy="0.0" r="1" fill="rgb(68,1,84)" stroke="rgb(68,1,84)"/><circle cx="1.0" cy="0.0" r="1" fill="red" stroke="red"/><circle cx="2.0" cy="0.0" r="1" fill="rgb(253,231,37)" stroke="rgb(253,231,37)"/>"#
+        );
+    }
+
+    #[test]
+    fn test_style_each_defs() {
+        let rects = [Rect::new((0.0, 0.0), (1.0, 1.0)).to_polygon()];
+        let styled = rects.style_each(|_, _| Style {
+            fill: Some(Pattern::dots().into()),
+            ..Style::default()
+        });
+        let svg = styled.to_svg().to_string();
+        assert!(svg.contains("<defs><pattern id="));
+        assert!(svg.contains(r#"fill="url(#pattern-"#));
+    }
+}
@@ -1,17 +1,62 @@
-use crate::{Color, LineCap, LineJoin, Style, ToSvgStr, Unit, ViewBox};
-use std::fmt::{Display, Formatter, Result};
+use crate::{
+    AspectRatio, Color, Colormap, FillRule, LineCap, LineJoin, NonFiniteError, NonFiniteItem,
+    Normalization, Palette, PathEncoding, Pattern, PrettyPrint, Projection, Shapes, Simplification,
+    SimplifyAlgorithm, Style, Theme, ToSvgStr, Unit, ViewBox, defs,
+};
+use geo_types::{CoordNum, Rect};
+use std::borrow::Cow;
+use std::fmt::{Display, Formatter, Result, Write};
+use std::rc::Rc;
 
 #[derive(Clone)]
 pub struct Svg<'a> {
     pub items: Vec<&'a dyn ToSvgStr>,
+    pub owned_items: Vec<Rc<dyn ToSvgStr + 'a>>,
     pub siblings: Vec<Svg<'a>>,
     pub viewbox: ViewBox,
     pub style: Style<'a>,
     pub width: Option<Unit>,
     pub height: Option<Unit>,
+    pub clip_path: Option<&'a dyn ToSvgStr>,
+    pub mask: Option<Box<Svg<'a>>>,
+    pub fixed_viewbox: Option<ViewBox>,
+    pub fallback_viewbox: ViewBox,
+    pub aspect_ratio: AspectRatio,
+    pub pretty_print: Option<PrettyPrint>,
+    pub layer: Option<&'a str>,
+    pub theme: Option<&'a Theme>,
 }
 
 impl<'a> Svg<'a> {
+    pub(crate) fn new(
+        items: Vec<&'a dyn ToSvgStr>,
+        owned_items: Vec<Rc<dyn ToSvgStr + 'a>>,
+    ) -> Self {
+        Svg {
+            items,
+            owned_items,
+            siblings: vec![],
+            viewbox: ViewBox::default(),
+            style: Style::default(),
+            width: None,
+            height: None,
+            clip_path: None,
+            mask: None,
+            fixed_viewbox: None,
+            fallback_viewbox: ViewBox::new(0.0, 0.0, 1.0, 1.0),
+            aspect_ratio: AspectRatio::default(),
+            pretty_print: None,
+            layer: None,
+            theme: None,
+        }
+    }
+
+    /// Same as [`ToSvg::to_svg`](crate::ToSvg::to_svg) but takes ownership of `item`, e.g. a
+    /// geometry parsed on the fly.
+    pub fn from_owned(item: impl ToSvgStr + 'a) -> Self {
+        Svg::new(vec![], vec![Rc::new(item)])
+    }
+
     pub fn and(mut self, sibling: Svg<'a>) -> Self {
         self.siblings.push(sibling);
         self
@@ -31,7 +76,7 @@ impl<'a> Svg<'a> {
     }
 
     pub fn with_color(mut self, color: Color<'a>) -> Self {
-        self.style.fill = Some(color);
+        self.style.fill = Some(color.into());
         self.style.stroke_color = Some(color);
         for sibling in &mut self.siblings {
             *sibling = sibling.clone().with_color(color);
@@ -39,6 +84,55 @@ impl<'a> Svg<'a> {
         self
     }
 
+    /// Gives each node of the tree of siblings its own fill and stroke color from `palette`,
+    /// in the order they were added.
+    pub fn with_palette(self, palette: Palette) -> Self {
+        let mut index = 0;
+        self.with_node_colors(&mut || {
+            index += 1;
+            Some(palette.color(index - 1))
+        })
+    }
+
+    /// Colors each node of the tree of siblings from the value returned by `value` for its
+    /// index, in the order they were added, normalized relative to the values of the other
+    /// nodes.
+    ///
+    /// Nodes whose value cannot be normalized, such as NaN, keep their colors. A collection
+    /// rendered as one node, such as a `Vec`, is colored per member with
+    /// [`StyleEach::colormap_each`](crate::StyleEach::colormap_each) instead.
+    pub fn with_colormap(
+        self,
+        colormap: Colormap,
+        normalization: Normalization,
+        value: impl Fn(usize) -> f64,
+    ) -> Self {
+        let values: Vec<f64> = (0..self.node_count()).map(value).collect();
+        let mut colors = normalization
+            .normalize(&values)
+            .into_iter()
+            .map(|position| position.map(|position| colormap.color(position)));
+        self.with_node_colors(&mut || colors.next().flatten())
+    }
+
+    fn node_count(&self) -> usize {
+        1 + self.siblings.iter().map(Svg::node_count).sum::<usize>()
+    }
+
+    /// Sets the fill and stroke colors of each node to the next color of `colors`, in pre-order.
+    fn with_node_colors(mut self, colors: &mut impl FnMut() -> Option<Color<'a>>) -> Self {
+        if let Some(color) = colors() {
+            self.style.fill = Some(color.into());
+            self.style.stroke_color = Some(color);
+        }
+        self.siblings = self
+            .siblings
+            .into_iter()
+            .map(|sibling| sibling.with_node_colors(colors))
+            .collect();
+        self
+    }
+
     pub fn with_opacity(mut self, opacity: f32) -> Self {
         self.style.opacity = Some(opacity);
         for sibling in &mut self.siblings {
@@ -48,13 +142,21 @@ impl<'a> Svg<'a> {
     }
 
     pub fn with_fill_color(mut self, fill: Color<'a>) -> Self {
-        self.style.fill = Some(fill);
+        self.style.fill = Some(fill.into());
         for sibling in &mut self.siblings {
             *sibling = sibling.clone().with_fill_color(fill);
         }
         self
     }
 
+    pub fn with_fill_pattern(mut self, pattern: Pattern<'a>) -> Self {
+        self.style.fill = Some(pattern.clone().into());
+        for sibling in &mut self.siblings {
+            *sibling = sibling.clone().with_fill_pattern(pattern.clone());
+        }
+        self
+    }
+
     pub fn with_fill_opacity(mut self, fill_opacity: f32) -> Self {
         self.style.fill_opacity = Some(fill_opacity);
         for sibling in &mut self.siblings {
@@ -111,32 +213,357 @@ impl<'a> Svg<'a> {
         self
     }
 
+    /// Fill rule of polygons, see [`FillRule`].
+    pub fn with_fill_rule(mut self, fill_rule: FillRule) -> Self {
+        self.style.fill_rule = Some(fill_rule);
+        for sibling in &mut self.siblings {
+            *sibling = sibling.clone().with_fill_rule(fill_rule);
+        }
+        self
+    }
+
     pub fn with_radius(mut self, radius: f32) -> Self {
-        self.style.radius = radius;
+        self.style.radius = Some(radius);
         for sibling in &mut self.siblings {
             *sibling = sibling.clone().with_radius(radius);
         }
         self
     }
 
-    pub fn svg_str(&self) -> String {
-        self.items
+    /// Projects every coordinate, e.g. to render longitudes and latitudes without distorting
+    /// shapes at high latitudes.
+    ///
+    /// View boxes and viewports are given in projected coordinates.
+    pub fn with_projection(mut self, projection: Projection) -> Self {
+        self.style.projection = Some(projection.clone());
+        for sibling in &mut self.siblings {
+            *sibling = sibling.clone().with_projection(projection.clone());
+        }
+        self
+    }
+
+    /// Drops the vertices of lines and polygons which cannot be seen at the resolution of the
+    /// output, i.e. which move the outline by less than `tolerance` pixels.
+    ///
+    /// The size of a pixel is derived from the view box and the width or height of the image,
+    /// one SVG unit being one pixel when neither is set.
+    pub fn with_simplification(mut self, algorithm: SimplifyAlgorithm, tolerance: f32) -> Self {
+        self.style.simplification = Some(Simplification::new(algorithm, tolerance));
+        for sibling in &mut self.siblings {
+            *sibling = sibling.clone().with_simplification(algorithm, tolerance);
+        }
+        self
+    }
+
+    /// How the `d` attribute of paths is written, see [`PathEncoding`].
+    pub fn with_path_encoding(mut self, path_encoding: PathEncoding) -> Self {
+        self.style.path_encoding = Some(path_encoding);
+        for sibling in &mut self.siblings {
+            *sibling = sibling.clone().with_path_encoding(path_encoding);
+        }
+        self
+    }
+
+    /// Which elements geometries are written as, see [`Shapes`].
+    pub fn with_shapes(mut self, shapes: Shapes) -> Self {
+        self.style.shapes = Some(shapes);
+        for sibling in &mut self.siblings {
+            *sibling = sibling.clone().with_shapes(shapes);
+        }
+        self
+    }
+
+    /// Rounds the corners of rectangles written as `<rect>` elements, see
+    /// [`Shapes::Primitives`].
+    pub fn with_corner_radius(mut self, rx: f32, ry: f32) -> Self {
+        self.style.corner_radius = Some((rx, ry));
+        for sibling in &mut self.siblings {
+            *sibling = sibling.clone().with_corner_radius(rx, ry);
+        }
+        self
+    }
+
+    /// Writes the polygons of each multi-polygon as a single path, so that their overlaps are
+    /// filled once and semi-transparent fills look uniform.
+    ///
+    /// The path is filled with [`FillRule::NonZero`] unless a fill rule is set, holes then only
+    /// being left out if they wind the other way around than their exterior. With
+    /// [`FillRule::EvenOdd`], overlaps are cut out as holes instead.
+    pub fn with_single_path(mut self, single_path: bool) -> Self {
+        self.style.single_path = Some(single_path);
+        for sibling in &mut self.siblings {
+            *sibling = sibling.clone().with_single_path(single_path);
+        }
+        self
+    }
+
+    fn with_pixel_size(mut self, pixel_size: f32) -> Self {
+        if let Some(simplification) = &mut self.style.simplification {
+            simplification.pixel_size = pixel_size;
+        }
+        self.mask = self
+            .mask
+            .map(|mask| Box::new(mask.with_pixel_size(pixel_size)));
+        for sibling in &mut self.siblings {
+            *sibling = sibling.clone().with_pixel_size(pixel_size);
+        }
+        self
+    }
+
+    /// Size of a pixel of the rendered image in SVG units, if the image has a width or a height.
+    ///
+    /// When both are set, the view box is scaled by the smaller of the ratios of the image to
+    /// the view box with `Meet`, the larger with `Slice`, and by each of them along its own
+    /// axis with `Stretch`, in which case the finer pixel size is kept.
+    fn pixel_size(&self, viewbox: &ViewBox) -> Option<f32> {
+        let width = self.width.map(|width| viewbox.width() / width.to_pixels());
+        let height = self
+            .height
+            .map(|height| viewbox.height() / height.to_pixels());
+        match (width, height) {
+            (Some(width), Some(height)) => Some(match self.aspect_ratio {
+                AspectRatio::Meet(_) => width.max(height),
+                AspectRatio::Slice(_) | AspectRatio::Stretch => width.min(height),
+            }),
+            (Some(size), None) | (None, Some(size)) => Some(size),
+            (None, None) => None,
+        }
+    }
+
+    /// Renders exactly `viewbox` instead of fitting the view box to the content, e.g. to show
+    /// the same map extent in every frame of a comparison.
+    ///
+    /// Unlike [`Svg::with_viewport`], geometries are not clipped.
+    pub fn with_viewbox(mut self, viewbox: ViewBox) -> Self {
+        self.fixed_viewbox = Some(viewbox);
+        self
+    }
+
+    /// View box rendered when the content has no extent, e.g. when all the geometries are
+    /// empty, instead of the default `0 0 1 1`.
+    pub fn with_fallback_viewbox(mut self, viewbox: ViewBox) -> Self {
+        self.fallback_viewbox = viewbox;
+        self
+    }
+
+    /// Same as [`Svg::with_viewbox`] with the extent given as a rectangle.
+    pub fn with_extent<T: CoordNum>(self, extent: Rect<T>) -> Self {
+        self.with_viewbox(extent.into())
+    }
+
+    /// How the view box is fitted into the image when their aspect ratios differ.
+    pub fn with_aspect_ratio(mut self, aspect_ratio: AspectRatio) -> Self {
+        self.aspect_ratio = aspect_ratio;
+        self
+    }
+
+    /// Writes one element per line, indented after the tree of groups, instead of the default
+    /// single line.
+    pub fn with_pretty_print(mut self, pretty_print: PrettyPrint) -> Self {
+        self.pretty_print = Some(pretty_print);
+        self
+    }
+
+    /// Clips lines and polygons to `viewport` and leaves out the geometries lying entirely
+    /// outside of it, which keeps the output small when only a window of a large dataset is
+    /// shown.
+    ///
+    /// The computed view box is restricted to the viewport.
+    pub fn with_viewport(mut self, viewport: ViewBox) -> Self {
+        self.style.viewport = Some(viewport);
+        for sibling in &mut self.siblings {
+            *sibling = sibling.clone().with_viewport(viewport);
+        }
+        self
+    }
+
+    /// Only shows the parts of this SVG lying inside `region`, typically a `Polygon` or a
+    /// `MultiPolygon`.
+    ///
+    /// The region is emitted as a `<clipPath>` and the computed view box is restricted to it.
+    pub fn with_clip_path(mut self, region: &'a dyn ToSvgStr) -> Self {
+        self.clip_path = Some(region);
+        for sibling in &mut self.siblings {
+            *sibling = sibling.clone().with_clip_path(region);
+        }
+        self
+    }
+
+    /// Uses the luminance of another SVG as the opacity of this one, white being fully visible
+    /// and black fully hidden.
+    ///
+    /// The mask is emitted as a `<mask>` and the computed view box is restricted to it.
+    pub fn with_mask(mut self, mask: Svg<'a>) -> Self {
+        for sibling in &mut self.siblings {
+            *sibling = sibling.clone().with_mask(mask.clone());
+        }
+        self.mask = Some(Box::new(mask));
+        self
+    }
+
+    /// Tags this SVG and its siblings as part of the layer `name`, styled by the [`Theme`] given
+    /// to [`Svg::with_theme`].
+    pub fn with_layer(mut self, name: &'a str) -> Self {
+        self.layer = Some(name);
+        for sibling in &mut self.siblings {
+            *sibling = sibling.clone().with_layer(name);
+        }
+        self
+    }
+
+    /// Styles the nodes tagged with [`Svg::with_layer`] from `theme` when rendering, the fields
+    /// set in the style of their layer overriding their own.
+    pub fn with_theme(mut self, theme: &'a Theme) -> Self {
+        self.theme = Some(theme);
+        for sibling in &mut self.siblings {
+            *sibling = sibling.clone().with_theme(theme);
+        }
+        self
+    }
+
+    /// Style of the node, overridden by the style of its layer in the theme, if any.
+    fn node_style(&self) -> Cow<'_, Style<'a>> {
+        match self.theme.zip(self.layer) {
+            Some((theme, layer)) => match theme.layer(layer) {
+                Some(rule) => Cow::Owned(rule.clone().inherit(&self.style)),
+                None => Cow::Borrowed(&self.style),
+            },
+            None => Cow::Borrowed(&self.style),
+        }
+    }
+
+    /// Borrowed and owned items of this node.
+    fn all_items(&self) -> impl Iterator<Item = &dyn ToSvgStr> {
+        let owned = self
+            .owned_items
             .iter()
-            .map(|item| item.to_svg_str(&self.style))
-            .chain(self.siblings.iter().map(Svg::svg_str))
-            .collect()
+            .map(|item| item.as_ref() as &dyn ToSvgStr);
+        self.items.iter().copied().chain(owned)
+    }
+
+    pub fn svg_str(&self) -> String {
+        let style = self.node_style();
+        let mut items: String = self
+            .all_items()
+            .map(|item| item.to_svg_str(&style))
+            .collect();
+        if !items.is_empty() {
+            let mut attributes = String::new();
+            if let Some((id, _)) = self.clip_path_def() {
+                attributes += &format!(r#" clip-path="url(#{id})""#);
+            }
+            if let Some((id, _)) = self.mask_def() {
+                attributes += &format!(r#" mask="url(#{id})""#);
+            }
+            if !attributes.is_empty() {
+                items = format!("<g{attributes}>{items}</g>");
+            }
+        }
+        items + &self.siblings.iter().map(Svg::svg_str).collect::<String>()
+    }
+
+    /// Definitions referenced by this SVG and its siblings, without duplicates.
+    pub fn defs(&self) -> Vec<String> {
+        let style = self.node_style();
+        defs::dedup(
+            self.all_items()
+                .flat_map(|item| item.defs(&style))
+                .chain(self.clip_path_def().map(|(_, def)| def))
+                .chain(self.mask.iter().flat_map(|mask| mask.defs()))
+                .chain(self.mask_def().map(|(_, def)| def))
+                .chain(self.siblings.iter().flat_map(Svg::defs))
+                .collect(),
+        )
+    }
+
+    fn clip_path_def(&self) -> Option<(String, String)> {
+        let region = self.clip_path?.to_svg_str(&self.node_style());
+        let id = defs::id("clip", &region);
+        let def = format!(r#"<clipPath id="{id}" clip-rule="evenodd">{region}</clipPath>"#);
+        Some((id, def))
+    }
+
+    fn mask_def(&self) -> Option<(String, String)> {
+        let mask = self.mask.as_ref()?;
+        let viewbox = mask.viewbox();
+        let content = mask.svg_str();
+        let id = defs::id("mask", &content);
+        let def = format!(
+            r#"<mask id="{id}" maskUnits="userSpaceOnUse" x="{x}" y="{y}" width="{w}" height="{h}">{content}</mask>"#,
+            x = viewbox.min_x(),
+            y = viewbox.min_y(),
+            w = viewbox.width(),
+            h = viewbox.height(),
+        );
+        Some((id, def))
     }
 
     pub fn viewbox(&self) -> ViewBox {
-        self.items
+        if let Some(viewbox) = self.fixed_viewbox {
+            return viewbox;
+        }
+        let style = self.node_style();
+        let mut viewbox = self.all_items().fold(ViewBox::default(), |viewbox, item| {
+            viewbox.add(&item.viewbox(&style))
+        });
+        if let Some(viewport) = style.viewport {
+            viewbox = viewbox.intersection(&viewport);
+        }
+        if let Some(region) = self.clip_path {
+            let style = Style {
+                stroke_width: Some(0.0),
+                ..(*style).clone()
+            };
+            viewbox = viewbox.intersection(&region.viewbox(&style));
+        }
+        if let Some(mask) = &self.mask {
+            viewbox = viewbox.intersection(&mask.viewbox());
+        }
+        self.siblings
             .iter()
-            .map(|item| item.viewbox(&self.style))
-            .chain(self.siblings.iter().map(Svg::viewbox))
-            .fold(self.viewbox, |viewbox, other_viewbox| {
+            .map(Svg::viewbox)
+            .fold(self.viewbox.add(&viewbox), |viewbox, other_viewbox| {
                 viewbox.add(&other_viewbox)
             })
     }
 
+    /// Items of this SVG and its siblings holding NaN or infinite coordinates once projected,
+    /// which are left out of the output of `Display`.
+    pub fn non_finite_items(&self) -> Vec<NonFiniteItem> {
+        let mut items = vec![];
+        self.collect_non_finite_items(&mut 0, &mut items);
+        items
+    }
+
+    fn collect_non_finite_items(&self, node: &mut usize, items: &mut Vec<NonFiniteItem>) {
+        let style = self.node_style();
+        items.extend(self.all_items().enumerate().filter_map(|(item, value)| {
+            let coords = value.non_finite_coords(&style);
+            (coords > 0).then_some(NonFiniteItem {
+                node: *node,
+                item,
+                coords,
+            })
+        }));
+        *node += 1;
+        for sibling in &self.siblings {
+            sibling.collect_non_finite_items(node, items);
+        }
+    }
+
+    /// Same as `to_string` but fails, listing the offending items, if some coordinates are NaN
+    /// or infinite once projected instead of leaving them out.
+    pub fn try_to_string(&self) -> std::result::Result<String, NonFiniteError> {
+        let items = self.non_finite_items();
+        if !items.is_empty() {
+            return Err(NonFiniteError { items });
+        }
+        let mut svg = String::new();
+        self.write(&mut svg)
+            .expect("writing to a string without non-finite coordinates succeeds");
+        Ok(svg)
+    }
+
     /// Typically only `set_width` or `set_height is necessary.
     /// The other quantity is computed to match the aspect ratio of the view box.
     pub fn set_width(&mut self, width: Unit) {
@@ -150,14 +577,30 @@ impl<'a> Svg<'a> {
     }
 }
 
-impl Display for Svg<'_> {
-    fn fmt(&self, fmt: &mut Formatter) -> Result {
-        let viewbox = self.viewbox();
+impl Svg<'_> {
+    fn write(&self, fmt: &mut impl Write) -> Result {
+        match &self.pretty_print {
+            Some(pretty_print) => {
+                let mut svg = String::new();
+                self.write_compact(&mut svg)?;
+                fmt.write_str(&pretty_print.format(&svg))
+            }
+            None => self.write_compact(fmt),
+        }
+    }
+
+    /// Writes the whole document on a single line.
+    fn write_compact(&self, fmt: &mut impl Write) -> Result {
+        let mut viewbox = self.viewbox();
+        if viewbox.is_empty() || (viewbox.width() == 0.0 && viewbox.height() == 0.0) {
+            viewbox = self.fallback_viewbox;
+        }
         let w = viewbox.width();
         let h = viewbox.height();
         write!(
             fmt,
-            r#"<svg xmlns="http://www.w3.org/2000/svg" preserveAspectRatio="xMidYMid meet" viewBox="{x} {y} {w} {h}""#,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" preserveAspectRatio="{aspect_ratio}" viewBox="{x} {y} {w} {h}""#,
+            aspect_ratio = self.aspect_ratio,
             x = viewbox.min_x(),
             y = viewbox.min_y(),
         )?;
@@ -173,6 +616,272 @@ impl Display for Svg<'_> {
                     .unwrap_or_else(|| self.width.unwrap().scale(h / w)),
             )?;
         }
-        write!(fmt, r#">{content}</svg>"#, content = self.svg_str())
+        fmt.write_str(">")?;
+        // a nested SVG without a size of its own keeps the pixel size of the enclosing one
+        let svg = match self.pixel_size(&viewbox) {
+            Some(pixel_size) => self.clone().with_pixel_size(pixel_size),
+            None => self.clone(),
+        };
+        let defs = svg.defs();
+        if !defs.is_empty() {
+            write!(fmt, "<defs>{}</defs>", defs.concat())?;
+        }
+        write!(fmt, r#"{content}</svg>"#, content = svg.svg_str())
+    }
+}
+
+impl Display for Svg<'_> {
+    fn fmt(&self, fmt: &mut Formatter) -> Result {
+        self.write(fmt)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Align, AspectRatio, Color, Colormap, NonFiniteItem, Normalization, PrettyPrint,
+        SimplifyAlgorithm, Style, Theme, ToSvg, Unit, ViewBox,
+    };
+    use geo_types::{Line, LineString, Point, Polygon, Rect};
+
+    #[test]
+    fn test_clip_path() {
+        let points = vec![Point::new(0.0, 0.0), Point::new(100.0, 100.0)];
+        let area = Polygon::new(
+            LineString::from(vec![
+                (-10.0, -10.0),
+                (10.0, -10.0),
+                (10.0, 10.0),
+                (-10.0, 10.0),
+            ]),
+            vec![],
+        );
+        let svg = points.to_svg().with_clip_path(&area);
+        assert_eq!(svg.viewbox(), ViewBox::new(-2.0, -2.0, 10.0, 10.0));
+        let svg = svg.to_string();
+        assert!(svg.contains(r#"<defs><clipPath id="clip-"#));
+        assert!(svg.contains(r#"<g clip-path="url(#clip-"#));
+    }
+
+    #[test]
+    fn test_clip_path_is_applied_to_siblings() {
+        let rect = Rect::new((0.0, 0.0), (10.0, 10.0));
+        let area = Rect::new((0.0, 0.0), (5.0, 5.0));
+        let svg = rect
+            .to_svg()
+            .and(rect.to_svg())
+            .with_clip_path(&area)
+            .to_string();
+        assert_eq!(svg.matches("<clipPath").count(), 1);
+        assert_eq!(svg.matches("<g clip-path").count(), 2);
+    }
+
+    #[test]
+    fn test_mask() {
+        let rect = Rect::new((0.0, 0.0), (10.0, 10.0));
+        let circle = Point::new(5.0, 5.0);
+        let mask = circle
+            .to_svg()
+            .with_radius(3.0)
+            .with_fill_color(Color::Named("white"));
+        let svg = rect.to_svg().with_mask(mask);
+        assert_eq!(svg.viewbox(), ViewBox::new(1.0, 1.0, 9.0, 9.0));
+        let svg = svg.to_string();
+        assert!(svg.contains(
+            r#"maskUnits="userSpaceOnUse" x="1" y="1" width="8" height="8"><circle cx="5.0" cy="5.0" r="3" fill="white"/></mask>"#
+        ));
+        assert!(svg.contains(r#"<g mask="url(#mask-"#));
+    }
+
+    #[test]
+    fn test_fixed_viewbox() {
+        let point = Point::new(50.0, 50.0);
+        let svg = point
+            .to_svg()
+            .with_extent(Rect::new((0.0, 0.0), (10.0, 20.0)))
+            .with_aspect_ratio(AspectRatio::Slice(Align::XMinYMin));
+        assert_eq!(svg.viewbox(), ViewBox::new(0.0, 0.0, 10.0, 20.0));
+        assert!(svg.to_string().starts_with(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" preserveAspectRatio="xMinYMin slice" viewBox="0 0 10 20">"#
+        ));
+    }
+
+    #[test]
+    fn test_simplification_depends_on_image_size() {
+        let zigzag: LineString = (0..=100)
+            .map(|i| (f64::from(i), if i % 2 == 0 { 0.0 } else { 1.0 }))
+            .collect();
+        let mut svg = zigzag
+            .to_svg()
+            .with_simplification(SimplifyAlgorithm::DouglasPeucker, 0.5);
+        svg.set_width(Unit::Pixel(1000.0));
+        assert_eq!(svg.to_string().matches(" L ").count(), 100);
+        svg.set_width(Unit::Pixel(10.0));
+        assert_eq!(svg.to_string().matches(" L ").count(), 1);
+
+        // the 10 pixels of the width are what the view box is fitted into
+        svg.set_height(Unit::Pixel(1000.0));
+        assert_eq!(svg.to_string().matches(" L ").count(), 1);
+        svg.aspect_ratio = AspectRatio::Slice(Align::XMidYMid);
+        assert_eq!(svg.to_string().matches(" L ").count(), 100);
+
+        // nested SVGs are simplified at the resolution of the image
+        let inner = zigzag.to_svg();
+        let mut outer = inner
+            .to_svg()
+            .with_simplification(SimplifyAlgorithm::DouglasPeucker, 0.5)
+            .with_viewbox(ViewBox::new(-1.0, -1.0, 101.0, 2.0));
+        outer.set_width(Unit::Pixel(10.0));
+        assert_eq!(outer.to_string().matches(" L ").count(), 1);
+    }
+
+    #[test]
+    fn test_pretty_print() {
+        let polygon = Polygon::new(
+            LineString::from(vec![(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)]),
+            vec![],
+        );
+        let point = Point::new(5.0, 5.0);
+        let svg = polygon.to_svg().with_clip_path(&point).and(point.to_svg());
+        let pretty = svg
+            .clone()
+            .with_pretty_print(PrettyPrint::default())
+            .to_string();
+        assert_eq!(
+            pretty.lines().map(str::trim_start).collect::<String>(),
+            svg.to_string()
+        );
+        let pretty = svg
+            .with_pretty_print(PrettyPrint::default().with_path_wrap(30))
+            .to_string();
+        assert!(pretty.starts_with("<svg "));
+        assert!(pretty.contains("\n  <g clip-path="));
+        assert!(pretty.contains(
+            "\n    <path fill-rule=\"evenodd\" d=\"M 0.0 0.0 L 10.0 0.0\n        L 10.0 10.0"
+        ));
+        assert!(pretty.ends_with("\n</svg>"));
+    }
+
+    #[test]
+    fn test_colormap() {
+        let points = [
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(2.0, 0.0),
+        ];
+        let values = [10.0, f64::NAN, 30.0];
+        let svg = points[0]
+            .to_svg()
+            .and(points[1].to_svg().with_color(Color::Named("red")))
+            .and(points[2].to_svg())
+            .with_colormap(Colormap::Viridis, Normalization::Linear, |index| {
+                values[index]
+            })
+            .svg_str();
+        assert_eq!(
+            svg,
+            r#"<circle cx="0.0" cy="0.0" r="1" fill="rgb(68,1,84)" stroke="rgb(68,1,84)"/><circle cx="1.0" cy="0.0" r="1" fill="red" stroke="red"/><circle cx="2.0" cy="0.0" r="1" fill="rgb(253,231,37)" stroke="rgb(253,231,37)"/>"#
+        );
+    }
+
+    #[test]
+    fn test_theme() {
+        let theme = Theme::default().with_layer(
+            "roads",
+            Style {
+                stroke_color: Some(Color::GRAY),
+                stroke_width: Some(2.0),
+                ..Style::default()
+            },
+        );
+        let road = LineString::from(vec![(0.0, 0.0), (10.0, 0.0)]);
+        let error = Point::new(5.0, 0.0);
+        let svg = road
+            .to_svg()
+            .with_stroke_width(0.5)
+            .with_fill_opacity(0.1)
+            .with_layer("roads")
+            .and(error.to_svg().with_layer("debug-errors"))
+            .with_theme(&theme);
+        assert_eq!(
+            svg.svg_str(),
+            r#"<path d="M 0.0 0.0 L 10.0 0.0" fill-opacity="0.1" stroke="gray" stroke-width="2"/><circle cx="5.0" cy="0.0" r="1"/>"#
+        );
+        assert_eq!(svg.viewbox().max_x(), 12.0);
+
+        let markers = Theme::default().with_layer(
+            "markers",
+            Style {
+                radius: Some(1.0),
+                ..Style::default()
+            },
+        );
+        let svg = error
+            .to_svg()
+            .with_radius(5.0)
+            .with_layer("markers")
+            .with_theme(&markers);
+        assert_eq!(svg.svg_str(), r#"<circle cx="5.0" cy="0.0" r="1"/>"#);
+    }
+
+    #[test]
+    fn test_fallback_viewbox() {
+        let empty = LineString::<f64>::new(vec![]);
+        assert!(empty.to_svg().to_string().contains(r#"viewBox="0 0 1 1">"#));
+        let svg = empty
+            .to_svg()
+            .with_fallback_viewbox(ViewBox::new(-5.0, -5.0, 5.0, 5.0))
+            .to_string();
+        assert!(svg.contains(r#"viewBox="-5 -5 10 10">"#));
+    }
+
+    #[test]
+    fn test_non_finite_coordinates() {
+        let point = Point::new(f64::NAN, 0.0);
+        let line_string = LineString::from(vec![(0.0, 0.0), (f64::INFINITY, 5.0), (10.0, 0.0)]);
+        let svg = point.to_svg().and(line_string.to_svg());
+        assert_eq!(svg.svg_str(), r#"<path d="M 0.0 0.0 L 10.0 0.0"/>"#);
+        assert_eq!(svg.viewbox(), ViewBox::new(-1.0, -1.0, 11.0, 1.0));
+        assert_eq!(
+            svg.non_finite_items(),
+            [
+                NonFiniteItem {
+                    node: 0,
+                    item: 0,
+                    coords: 1
+                },
+                NonFiniteItem {
+                    node: 1,
+                    item: 0,
+                    coords: 1
+                }
+            ]
+        );
+        let error = svg.try_to_string().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "1 non-finite coordinate(s) in item 0 of node 0, 1 non-finite coordinate(s) in item 0 of node 1"
+        );
+        assert!(svg.to_string().contains("M 0.0 0.0 L 10.0 0.0"));
+
+        // skipping must not leave a point which is not in the data
+        let nan_line = Line::new((0.0, 0.0), (f64::NAN, 0.0));
+        let nan_line_string = LineString::from(vec![(0.0, 0.0), (f64::NAN, 5.0)]);
+        let nan_triangle = Polygon::new(
+            LineString::from(vec![(0.0, 0.0), (f64::NAN, 0.0), (0.0, f64::NAN)]),
+            vec![],
+        );
+        let svg = nan_line
+            .to_svg()
+            .and(nan_line_string.to_svg())
+            .and(nan_triangle.to_svg());
+        assert_eq!(svg.svg_str(), "");
+        assert!(svg.viewbox().is_empty());
+
+        let finite = Point::new(1.0, 1.0);
+        assert_eq!(
+            finite.to_svg().try_to_string(),
+            Ok(finite.to_svg().to_string())
+        );
     }
 }
@@ -1,238 +1,638 @@
-use crate::{Style, ToSvgStr, ViewBox};
+use crate::cast::to_f64;
+use crate::clip::{ClipRect, Visibility};
+use crate::non_finite::{is_finite, non_finite};
+use crate::path::{PathEncoding, Shapes, compact};
+use crate::projection::project;
+use crate::simplify::simplify;
+use crate::{FillRule, Style, ToSvgStr, ViewBox};
+use geo_traits::{
+    CoordTrait, GeometryCollectionTrait, GeometryTrait, GeometryType, LineStringTrait, LineTrait,
+    MultiLineStringTrait, MultiPointTrait, MultiPolygonTrait, PointTrait, PolygonTrait, RectTrait,
+    TriangleTrait,
+};
 use geo_types::{
     Coord, CoordNum, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
     MultiPolygon, Point, Polygon, Rect, Triangle,
 };
 use num_traits::NumCast;
 
-impl<T: CoordNum> ToSvgStr for Coord<T> {
-    fn to_svg_str(&self, style: &Style) -> String {
-        Point::from(*self).to_svg_str(style)
-    }
+// the `geo_types` geometries are rendered through their `geo_traits` implementation, as any
+// other geometry is with `AnyGeometry`
+macro_rules! impl_to_svg_str {
+    ($($geometry:ident),*) => {
+        $(
+            impl<T: CoordNum> ToSvgStr for $geometry<T> {
+                fn to_svg_str(&self, style: &Style) -> String {
+                    geometry_svg_str(self, style)
+                }
 
-    fn viewbox(&self, style: &Style) -> ViewBox {
-        Point::from(*self).viewbox(style)
-    }
+                fn viewbox(&self, style: &Style) -> ViewBox {
+                    geometry_viewbox(self, style)
+                }
+
+                fn non_finite_coords(&self, style: &Style) -> usize {
+                    geometry_non_finite_coords(self, style)
+                }
+            }
+        )*
+    };
 }
 
-impl<T: CoordNum> ToSvgStr for Point<T> {
-    fn to_svg_str(&self, style: &Style) -> String {
-        format!(
-            r#"<circle cx="{x:?}" cy="{y:?}" r="{radius}"{style}/>"#,
-            x = self.x(),
-            y = self.y(),
-            radius = style.radius,
-            style = style,
-        )
+impl_to_svg_str!(
+    Point,
+    Line,
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    Rect,
+    Triangle,
+    Geometry,
+    GeometryCollection
+);
+
+pub(crate) fn geometry_svg_str<G: GeometryTrait<T: CoordNum>>(
+    geometry: &G,
+    style: &Style,
+) -> String {
+    match geometry.as_type() {
+        GeometryType::Point(point) => point.coord().map_or_else(String::new, |point| {
+            point_svg_str(project(style, coord(point)), style)
+        }),
+        GeometryType::Line(line) => line_svg_str(coord(line.start()), coord(line.end()), style),
+        GeometryType::LineString(line_string) => {
+            line_string_svg_str(line_string.coords().map(coord), style)
+        }
+        GeometryType::Polygon(polygon) => polygon_svg_str(rings(polygon), style),
+        GeometryType::MultiPoint(multi_point) => multi_point
+            .points()
+            .filter_map(|point| point.coord().map(coord))
+            .map(|point| point_svg_str(project(style, point), style))
+            .collect(),
+        GeometryType::MultiLineString(multi_line_string) => multi_line_string
+            .line_strings()
+            .map(|line_string| line_string_svg_str(line_string.coords().map(coord), style))
+            .collect(),
+        GeometryType::MultiPolygon(multi_polygon) => multi_polygon_svg_str(
+            multi_polygon
+                .polygons()
+                .map(|polygon| polygon_contours(rings(&polygon), style)),
+            style,
+        ),
+        GeometryType::GeometryCollection(collection) => collection
+            .geometries()
+            .map(|geometry| geometry_svg_str(&geometry, style))
+            .collect(),
+        GeometryType::Rect(rect) => {
+            let (min, max) = (coord(rect.min()), coord(rect.max()));
+            rect_svg_str(min, max, style)
+                .unwrap_or_else(|| polygon_svg_str([rect_ring(min, max)], style))
+        }
+        GeometryType::Triangle(triangle) => polygon_svg_str([triangle_ring(triangle)], style),
     }
+}
 
-    fn viewbox(&self, style: &Style) -> ViewBox {
-        let radius = style.radius + style.stroke_width.unwrap_or(1.0);
-        ViewBox::new(
-            NumCast::from(self.x()).unwrap_or(0f32) - radius,
-            NumCast::from(self.y()).unwrap_or(0f32) - radius,
-            NumCast::from(self.x()).unwrap_or(0f32) + radius,
-            NumCast::from(self.y()).unwrap_or(0f32) + radius,
-        )
+pub(crate) fn geometry_viewbox<G: GeometryTrait<T: CoordNum>>(
+    geometry: &G,
+    style: &Style,
+) -> ViewBox {
+    match geometry.as_type() {
+        GeometryType::Point(point) => point
+            .coord()
+            .map_or_else(ViewBox::default, |point| point_viewbox(coord(point), style)),
+        GeometryType::Line(line) => {
+            line_string_viewbox([coord(line.start()), coord(line.end())], style)
+        }
+        GeometryType::LineString(line_string) => {
+            line_string_viewbox(line_string.coords().map(coord), style)
+        }
+        GeometryType::Polygon(polygon) => polygon_viewbox(rings(polygon), style),
+        GeometryType::MultiPoint(multi_point) => multi_point
+            .points()
+            .filter_map(|point| point.coord().map(coord))
+            .fold(ViewBox::default(), |viewbox, point| {
+                viewbox.add(&point_viewbox(point, style))
+            }),
+        GeometryType::MultiLineString(multi_line_string) => {
+            multi_line_string
+                .line_strings()
+                .fold(ViewBox::default(), |viewbox, line_string| {
+                    viewbox.add(&line_string_viewbox(line_string.coords().map(coord), style))
+                })
+        }
+        GeometryType::MultiPolygon(multi_polygon) => multi_polygon
+            .polygons()
+            .fold(ViewBox::default(), |viewbox, polygon| {
+                viewbox.add(&polygon_viewbox(rings(&polygon), style))
+            }),
+        GeometryType::GeometryCollection(collection) => collection
+            .geometries()
+            .fold(ViewBox::default(), |viewbox, geometry| {
+                viewbox.add(&geometry_viewbox(&geometry, style))
+            }),
+        GeometryType::Rect(rect) => {
+            polygon_viewbox([rect_ring(coord(rect.min()), coord(rect.max()))], style)
+        }
+        GeometryType::Triangle(triangle) => polygon_viewbox([triangle_ring(triangle)], style),
     }
 }
 
-impl<T: CoordNum> ToSvgStr for MultiPoint<T> {
-    fn to_svg_str(&self, style: &Style) -> String {
-        self.0.iter().map(|point| point.to_svg_str(style)).collect()
+pub(crate) fn geometry_non_finite_coords<G: GeometryTrait<T: CoordNum>>(
+    geometry: &G,
+    style: &Style,
+) -> usize {
+    match geometry.as_type() {
+        GeometryType::Point(point) => non_finite(point.coord().map(coord), style),
+        GeometryType::Line(line) => non_finite([coord(line.start()), coord(line.end())], style),
+        GeometryType::LineString(line_string) => non_finite(line_string.coords().map(coord), style),
+        GeometryType::Polygon(polygon) => non_finite(rings(polygon).flatten(), style),
+        GeometryType::MultiPoint(multi_point) => non_finite(
+            multi_point
+                .points()
+                .filter_map(|point| point.coord().map(coord)),
+            style,
+        ),
+        GeometryType::MultiLineString(multi_line_string) => multi_line_string
+            .line_strings()
+            .map(|line_string| non_finite(line_string.coords().map(coord), style))
+            .sum(),
+        GeometryType::MultiPolygon(multi_polygon) => multi_polygon
+            .polygons()
+            .map(|polygon| non_finite(rings(&polygon).flatten(), style))
+            .sum(),
+        GeometryType::GeometryCollection(collection) => collection
+            .geometries()
+            .map(|geometry| geometry_non_finite_coords(&geometry, style))
+            .sum(),
+        GeometryType::Rect(rect) => non_finite([coord(rect.min()), coord(rect.max())], style),
+        GeometryType::Triangle(triangle) => non_finite(triangle_ring(triangle), style),
     }
+}
 
-    fn viewbox(&self, style: &Style) -> ViewBox {
-        self.0.iter().fold(ViewBox::default(), |view_box, point| {
-            view_box.add(&point.viewbox(style))
-        })
+fn coord<C: CoordTrait<T: CoordNum>>(coord: C) -> Coord<C::T> {
+    Coord {
+        x: coord.x(),
+        y: coord.y(),
     }
 }
 
-impl<T: CoordNum> ToSvgStr for Line<T> {
+/// Exterior then interior rings of the polygon, none if it has no exterior.
+fn rings<P: PolygonTrait<T: CoordNum>>(
+    polygon: &P,
+) -> impl Iterator<Item = impl Iterator<Item = Coord<P::T>>> {
+    polygon
+        .exterior()
+        .into_iter()
+        .flat_map(|exterior| std::iter::once(exterior).chain(polygon.interiors()))
+        .map(|ring| (0..ring.num_coords()).filter_map(move |index| ring.coord(index).map(coord)))
+}
+
+/// Ring of a rectangle, in the order of [`Rect::to_polygon`].
+fn rect_ring<T: CoordNum>(min: Coord<T>, max: Coord<T>) -> [Coord<T>; 5] {
+    [
+        (max.x, min.y),
+        (max.x, max.y),
+        (min.x, max.y),
+        (min.x, min.y),
+        (max.x, min.y),
+    ]
+    .map(Coord::from)
+}
+
+/// Unclosed ring of a triangle, closed by [`polygon_contours`].
+fn triangle_ring<R: TriangleTrait<T: CoordNum>>(triangle: &R) -> [Coord<R::T>; 3] {
+    [
+        coord(triangle.first()),
+        coord(triangle.second()),
+        coord(triangle.third()),
+    ]
+}
+
+impl<T: CoordNum> ToSvgStr for Coord<T> {
     fn to_svg_str(&self, style: &Style) -> String {
-        format!(
-            r#"<path d="M {x1:?} {y1:?} L {x2:?} {y2:?}"{style}/>"#,
-            x1 = self.start.x,
-            y1 = self.start.y,
-            x2 = self.end.x,
-            y2 = self.end.y,
-            style = style,
-        )
+        point_svg_str(project(style, *self), style)
     }
 
     fn viewbox(&self, style: &Style) -> ViewBox {
-        let style = Style {
-            radius: 0.0,
-            ..style.clone()
-        };
-        self.start.viewbox(&style).add(&self.end.viewbox(&style))
+        point_viewbox(*self, style)
+    }
+
+    fn non_finite_coords(&self, style: &Style) -> usize {
+        non_finite([*self], style)
     }
 }
 
-impl<T: CoordNum> ToSvgStr for LineString<T> {
-    fn to_svg_str(&self, style: &Style) -> String {
-        let len = self.0.len();
-        if len < 2 {
+/// Renders a projected coordinate as a circle.
+fn point_svg_str<T: CoordNum>(coord: Coord<T>, style: &Style) -> String {
+    if !is_finite(coord) {
+        return String::default();
+    }
+    let Coord { x, y } = coord;
+    if let Some(clip_rect) = ClipRect::from_style(style) {
+        // in f64, which unsigned coordinates near zero do not overflow in
+        let (center, radius) = (to_f64(coord), style.radius() as f64);
+        let corners = [
+            center - Coord::from((radius, radius)),
+            center + Coord::from((radius, radius)),
+        ];
+        if clip_rect.visibility(corners) == Visibility::Outside {
             return String::default();
         }
-        let delta = if self.is_closed() { 1 } else { 0 };
-        let path_string = self
-            .0
-            .iter()
-            .take(len.saturating_sub(delta))
-            .map(|p| format!("{x:?} {y:?}", x = p.x, y = p.y))
-            .enumerate()
-            .fold(String::from("M"), |s, (i, p)| {
-                let sep = if i == 0 { " " } else { " L " };
-                s + sep + p.as_str()
-            });
-        let end = if self.is_closed() { " Z" } else { "" };
-        format!(r#"<path d="{path}"{style}/>"#, path = path_string + end)
     }
+    format!(
+        r#"<circle cx="{x:?}" cy="{y:?}" r="{radius}"{style}/>"#,
+        radius = style.radius(),
+        style = style,
+    )
+}
 
-    fn viewbox(&self, style: &Style) -> ViewBox {
-        self.lines().fold(ViewBox::default(), |view_box, line| {
-            view_box.add(&line.viewbox(style))
-        })
+fn point_viewbox<T: CoordNum>(coord: Coord<T>, style: &Style) -> ViewBox {
+    let radius = style.radius() + style.stroke_width.unwrap_or(1.0);
+    let Coord { x, y } = project(style, coord);
+    if !is_finite(Coord { x, y }) {
+        return ViewBox::default();
     }
+    ViewBox::new(
+        NumCast::from(x).unwrap_or(0f32) - radius,
+        NumCast::from(y).unwrap_or(0f32) - radius,
+        NumCast::from(x).unwrap_or(0f32) + radius,
+        NumCast::from(y).unwrap_or(0f32) + radius,
+    )
 }
 
-impl<T: CoordNum> ToSvgStr for MultiLineString<T> {
-    fn to_svg_str(&self, style: &Style) -> String {
-        self.0
-            .iter()
-            .map(|line_string| line_string.to_svg_str(style))
-            .collect()
+fn line_svg_str<T: CoordNum>(start: Coord<T>, end: Coord<T>, style: &Style) -> String {
+    let (start, end) = (project(style, start), project(style, end));
+    if !is_finite(start) || !is_finite(end) {
+        return String::default();
+    }
+    // a line of zero length is shown as a point
+    if start == end {
+        return point_svg_str(start, style);
+    }
+    let (start, end) = match ClipRect::from_style(style) {
+        Some(clip_rect) => match clip_rect.clip_segment(start, end) {
+            Some(segment) => segment,
+            None => return String::default(),
+        },
+        None => (start, end),
+    };
+    if style.shapes == Some(Shapes::Primitives) {
+        return format!(
+            r#"<line x1="{x1:?}" y1="{y1:?}" x2="{x2:?}" y2="{y2:?}"{style}/>"#,
+            x1 = start.x,
+            y1 = start.y,
+            x2 = end.x,
+            y2 = end.y,
+        );
     }
+    if style.path_encoding == Some(PathEncoding::Compact) {
+        let path = compact(&[vec![start, end]], false);
+        return format!(r#"<path d="{path}"{style}/>"#);
+    }
+    format!(
+        r#"<path d="M {x1:?} {y1:?} L {x2:?} {y2:?}"{style}/>"#,
+        x1 = start.x,
+        y1 = start.y,
+        x2 = end.x,
+        y2 = end.y,
+        style = style,
+    )
+}
 
-    fn viewbox(&self, style: &Style) -> ViewBox {
-        self.0
-            .iter()
-            .fold(ViewBox::default(), |view_box, line_string| {
-                view_box.add(&line_string.viewbox(style))
-            })
+/// Renders the coordinates of a line string, closed if the first and last ones are equal.
+fn line_string_svg_str<T: CoordNum>(
+    coords: impl IntoIterator<Item = Coord<T>>,
+    style: &Style,
+) -> String {
+    projected_line_string_svg_str(finite_coords(coords, style), style)
+}
+
+/// Projected coordinates without the non-finite ones, none being left if that reduces them to a
+/// single point, which is not part of the data.
+fn finite_coords<T: CoordNum>(
+    coords: impl IntoIterator<Item = Coord<T>>,
+    style: &Style,
+) -> Vec<Coord<T>> {
+    let mut skipped = false;
+    let coords: Vec<Coord<T>> = coords
+        .into_iter()
+        .map(|coord| project(style, coord))
+        .filter(|coord| {
+            let finite = is_finite(*coord);
+            skipped |= !finite;
+            finite
+        })
+        .collect();
+    match coords.as_slice() {
+        [first, rest @ ..] if skipped && rest.iter().all(|coord| coord == first) => vec![],
+        _ => coords,
     }
 }
 
-impl<T: CoordNum> ToSvgStr for Polygon<T> {
-    fn to_svg_str(&self, style: &Style) -> String {
-        use std::fmt::Write;
-        let mut path = String::new();
-        for contour in std::iter::once(self.exterior()).chain(self.interiors().iter()) {
-            let mut points = contour.points();
-            if let Some(first_point) = points.next() {
-                write!(path, "M {:?} {:?}", first_point.x(), first_point.y()).unwrap()
-            }
-            for point in points {
-                write!(path, " L {:?} {:?}", point.x(), point.y()).unwrap();
-            }
-            write!(path, " Z ").unwrap();
+/// Renders projected coordinates as a line string, or as a point when they are all equal.
+fn projected_line_string_svg_str<T: CoordNum>(coords: Vec<Coord<T>>, style: &Style) -> String {
+    match coords.as_slice() {
+        [] => return String::default(),
+        [first, rest @ ..] if rest.iter().all(|coord| coord == first) => {
+            return point_svg_str(*first, style);
         }
-
-        format!(r#"<path fill-rule="evenodd" d="{path}"{style}/>"#)
+        _ => {}
+    }
+    let closed = coords.first() == coords.last();
+    let mut coords = simplify(style, coords, closed);
+    if closed {
+        coords.pop();
     }
+    let pieces = match ClipRect::from_style(style) {
+        Some(clip_rect) => match clip_rect.visibility(coords.iter().copied()) {
+            Visibility::Inside => vec![coords],
+            Visibility::Outside => vec![],
+            Visibility::Partial if closed => vec![clip_rect.clip_ring(&coords)],
+            Visibility::Partial => clip_rect.clip_line_string(&coords),
+        },
+        None => vec![coords],
+    };
+    let pieces: Vec<Vec<Coord<T>>> = pieces
+        .into_iter()
+        .filter(|piece| piece.len() >= 2)
+        .collect();
+    if pieces.is_empty() {
+        return String::default();
+    }
+    if style.shapes == Some(Shapes::Primitives) {
+        let element = if closed { "polygon" } else { "polyline" };
+        return pieces
+            .iter()
+            .map(|piece| format!(r#"<{element} points="{}"{style}/>"#, points(piece)))
+            .collect();
+    }
+    if style.path_encoding == Some(PathEncoding::Compact) {
+        let path = compact(&pieces, closed);
+        return format!(r#"<path d="{path}"{style}/>"#);
+    }
+    let path_string = pieces
+        .iter()
+        .map(|piece| {
+            piece
+                .iter()
+                .map(|p| format!("{x:?} {y:?}", x = p.x, y = p.y))
+                .enumerate()
+                .fold(String::from("M"), |s, (i, p)| {
+                    let sep = if i == 0 { " " } else { " L " };
+                    s + sep + p.as_str()
+                })
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+    let end = if closed { " Z" } else { "" };
+    format!(r#"<path d="{path}"{style}/>"#, path = path_string + end)
+}
 
-    fn viewbox(&self, style: &Style) -> ViewBox {
-        self.exterior()
-            .lines()
-            .chain(
-                self.interiors()
-                    .iter()
-                    .flat_map(|interior| interior.lines()),
+fn line_string_viewbox<T: CoordNum>(
+    coords: impl IntoIterator<Item = Coord<T>>,
+    style: &Style,
+) -> ViewBox {
+    // the coordinates are projected already
+    let point_style = Style {
+        projection: None,
+        ..style.clone()
+    };
+    let line_style = Style {
+        radius: Some(0.0),
+        ..point_style.clone()
+    };
+    let mut coords = finite_coords(coords, style).into_iter();
+    let Some(first) = coords.next() else {
+        return ViewBox::default();
+    };
+    let (degenerate, viewbox) = coords.fold(
+        (true, point_viewbox(first, &line_style)),
+        |(degenerate, viewbox), coord| {
+            (
+                degenerate && coord == first,
+                viewbox.add(&point_viewbox(coord, &line_style)),
             )
-            .fold(ViewBox::default(), |view_box, line_string| {
-                view_box.add(&line_string.viewbox(style))
-            })
+        },
+    );
+    // shown as a point, see `projected_line_string_svg_str`
+    if degenerate {
+        point_viewbox(first, &point_style)
+    } else {
+        viewbox
     }
 }
 
-impl<T: CoordNum> ToSvgStr for Rect<T> {
-    fn to_svg_str(&self, style: &Style) -> String {
-        Polygon::from(*self).to_svg_str(style)
-    }
+/// Renders the rings of a polygon, the first one being its exterior.
+pub(crate) fn polygon_svg_str<T: CoordNum>(
+    rings: impl IntoIterator<Item = impl IntoIterator<Item = Coord<T>>>,
+    style: &Style,
+) -> String {
+    polygon_contours_svg_str(polygon_contours(rings, style), style)
+}
 
-    fn viewbox(&self, style: &Style) -> ViewBox {
-        Polygon::from(*self).viewbox(style)
+fn polygon_contours_svg_str<T: CoordNum>(polygon: PolygonContours<T>, style: &Style) -> String {
+    match polygon {
+        PolygonContours::Contours(contours) => {
+            contours_svg_str(contours, style.fill_rule.unwrap_or_default(), style)
+        }
+        PolygonContours::Degenerate(svg_str) => svg_str,
     }
 }
 
-impl<T: CoordNum> ToSvgStr for Triangle<T> {
-    fn to_svg_str(&self, style: &Style) -> String {
-        Polygon::new(self.to_array().iter().cloned().collect(), vec![]).to_svg_str(style)
+/// Renders the polygons of a multi-polygon, given by their [`polygon_contours`], as a single
+/// path if [`Style::single_path`] is set.
+fn multi_polygon_svg_str<T: CoordNum>(
+    polygons: impl IntoIterator<Item = PolygonContours<T>>,
+    style: &Style,
+) -> String {
+    let polygons = polygons.into_iter();
+    if style.single_path != Some(true) {
+        return polygons
+            .map(|polygon| polygon_contours_svg_str(polygon, style))
+            .collect();
     }
-
-    fn viewbox(&self, style: &Style) -> ViewBox {
-        Polygon::new(self.to_array().iter().cloned().collect(), vec![]).viewbox(style)
+    let mut contours = vec![];
+    let mut degenerate = String::new();
+    for polygon in polygons {
+        match polygon {
+            PolygonContours::Contours(polygon) => contours.extend(polygon),
+            PolygonContours::Degenerate(svg_str) => degenerate += &svg_str,
+        }
     }
+    // overlapping polygons are filled once rather than cut out of each other by default
+    let fill_rule = style.fill_rule.unwrap_or(FillRule::NonZero);
+    contours_svg_str(contours, fill_rule, style) + &degenerate
 }
 
-impl<T: CoordNum> ToSvgStr for MultiPolygon<T> {
-    fn to_svg_str(&self, style: &Style) -> String {
-        self.0
-            .iter()
-            .map(|polygons| polygons.to_svg_str(style))
-            .collect()
-    }
+/// Closed contours of a polygon once projected, simplified and clipped.
+enum PolygonContours<T: CoordNum> {
+    Contours(Vec<Vec<Coord<T>>>),
+    /// Polygon without area, already rendered as a line or a point.
+    Degenerate(String),
+}
 
-    fn viewbox(&self, style: &Style) -> ViewBox {
-        self.0
-            .iter()
-            .fold(ViewBox::default(), |view_box, polygons| {
-                view_box.add(&polygons.viewbox(style))
-            })
+fn polygon_contours<T: CoordNum>(
+    rings: impl IntoIterator<Item = impl IntoIterator<Item = Coord<T>>>,
+    style: &Style,
+) -> PolygonContours<T> {
+    // rings are closed as in `geo_types`, which other implementors may not guarantee
+    let rings: Vec<Vec<Coord<T>>> = rings
+        .into_iter()
+        .map(|ring| {
+            let mut ring = finite_coords(ring, style);
+            if let (Some(&first), Some(&last)) = (ring.first(), ring.last())
+                && first != last
+            {
+                ring.push(first);
+            }
+            ring
+        })
+        .collect();
+    let exterior = rings.first().map_or(&[][..], Vec::as_slice);
+    if exterior.is_empty() {
+        return PolygonContours::Contours(vec![]);
+    }
+    // a polygon without area is shown as the line, or point, of its exterior
+    if collinear(exterior) {
+        let open = exterior[..exterior.len() - 1].to_vec();
+        return PolygonContours::Degenerate(projected_line_string_svg_str(open, style));
+    }
+    let visibility = ClipRect::from_style(style)
+        .map(|clip_rect| (clip_rect.visibility(exterior.iter().copied()), clip_rect));
+    let mut contours = rings;
+    contours.retain(|ring| !ring.is_empty());
+    for contour in &mut contours {
+        *contour = simplify(style, std::mem::take(contour), true);
     }
+    PolygonContours::Contours(match visibility {
+        Some((Visibility::Inside, _)) | None => contours,
+        Some((Visibility::Outside, _)) => vec![],
+        Some((Visibility::Partial, clip_rect)) => contours
+            .into_iter()
+            .map(|contour| {
+                let len = contour.len().saturating_sub(1);
+                let mut ring = clip_rect.clip_ring(&contour[..len]);
+                if let Some(&first) = ring.first() {
+                    ring.push(first);
+                }
+                ring
+            })
+            .filter(|ring| ring.len() > 3)
+            .collect(),
+    })
 }
 
-impl<T: CoordNum> ToSvgStr for Geometry<T> {
-    fn to_svg_str(&self, style: &Style) -> String {
-        use Geometry::*;
-        match self {
-            Point(point) => point.to_svg_str(style),
-            Line(line) => line.to_svg_str(style),
-            LineString(line_tring) => line_tring.to_svg_str(style),
-            Triangle(triangle) => triangle.to_polygon().to_svg_str(style),
-            Rect(rect) => rect.to_polygon().to_svg_str(style),
-            Polygon(polygon) => polygon.to_svg_str(style),
-            MultiPoint(multi_point) => multi_point.to_svg_str(style),
-            MultiLineString(multi_line_string) => multi_line_string.to_svg_str(style),
-            MultiPolygon(multi_polygon) => multi_polygon.to_svg_str(style),
-            GeometryCollection(geometry_collection) => geometry_collection.to_svg_str(style),
-        }
+/// Renders closed contours as a path filled with `fill_rule`.
+fn contours_svg_str<T: CoordNum>(
+    contours: Vec<Vec<Coord<T>>>,
+    fill_rule: FillRule,
+    style: &Style,
+) -> String {
+    use std::fmt::Write;
+    if contours.is_empty() {
+        return String::default();
+    }
+    // a single ring may overlap itself, which the fill rule applies to as well
+    if let (Some(Shapes::Primitives), [contour]) = (style.shapes, contours.as_slice()) {
+        let points = points(&contour[..contour.len() - 1]);
+        return format!(r#"<polygon fill-rule="{fill_rule}" points="{points}"{style}/>"#);
+    }
+    if style.path_encoding == Some(PathEncoding::Compact) {
+        let rings: Vec<Vec<Coord<T>>> = contours
+            .into_iter()
+            .map(|mut contour| {
+                contour.pop();
+                contour
+            })
+            .collect();
+        let path = compact(&rings, true);
+        return format!(r#"<path fill-rule="{fill_rule}" d="{path}"{style}/>"#);
     }
 
-    fn viewbox(&self, style: &Style) -> ViewBox {
-        use Geometry::*;
-        match self {
-            Point(point) => point.viewbox(style),
-            Line(line) => line.viewbox(style),
-            LineString(line_tring) => line_tring.viewbox(style),
-            Triangle(triangle) => triangle.to_polygon().viewbox(style),
-            Rect(rect) => rect.to_polygon().viewbox(style),
-            Polygon(polygon) => polygon.viewbox(style),
-            MultiPoint(multi_point) => multi_point.viewbox(style),
-            MultiLineString(multi_line_string) => multi_line_string.viewbox(style),
-            MultiPolygon(multi_polygon) => multi_polygon.viewbox(style),
-            GeometryCollection(geometry_collection) => geometry_collection.viewbox(style),
+    let mut path = String::new();
+    for contour in contours {
+        let mut points = contour.iter();
+        if let Some(first_point) = points.next() {
+            write!(path, "M {:?} {:?}", first_point.x, first_point.y).unwrap()
+        }
+        for point in points {
+            write!(path, " L {:?} {:?}", point.x, point.y).unwrap();
         }
+        write!(path, " Z ").unwrap();
     }
+
+    format!(r#"<path fill-rule="{fill_rule}" d="{path}"{style}/>"#)
 }
 
-impl<T: CoordNum> ToSvgStr for GeometryCollection<T> {
-    fn to_svg_str(&self, style: &Style) -> String {
-        self.0
-            .iter()
-            .map(|geometry| geometry.to_svg_str(style))
-            .collect()
+/// Value of the `points` attribute of a `<polygon>` or a `<polyline>`.
+fn points<T: CoordNum>(coords: &[Coord<T>]) -> String {
+    coords
+        .iter()
+        .map(|coord| format!("{x:?},{y:?}", x = coord.x, y = coord.y))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn polygon_viewbox<T: CoordNum>(
+    rings: impl IntoIterator<Item = impl IntoIterator<Item = Coord<T>>>,
+    style: &Style,
+) -> ViewBox {
+    let mut rings = rings.into_iter();
+    let exterior = rings.next().map_or_else(ViewBox::default, |exterior| {
+        line_string_viewbox(exterior, style)
+    });
+    if exterior.is_empty() {
+        return exterior;
     }
+    rings.fold(exterior, |viewbox, ring| {
+        viewbox.add(&line_string_viewbox(ring, style))
+    })
+}
 
-    fn viewbox(&self, style: &Style) -> ViewBox {
-        self.0
-            .iter()
-            .fold(ViewBox::default(), |view_box, geometry| {
-                view_box.add(&geometry.viewbox(style))
-            })
+/// Whether the coordinates all lie on a single line, or are all equal. Unlike a zero signed
+/// area, this leaves out self-intersecting rings such as bowties, whose parts cancel out.
+fn collinear<T: CoordNum>(coords: &[Coord<T>]) -> bool {
+    let Some(&origin) = coords.first() else {
+        return true;
+    };
+    let Some(&direction) = coords.iter().find(|coord| **coord != origin) else {
+        return true;
+    };
+    let (origin, direction) = (to_f64(origin), to_f64(direction) - to_f64(origin));
+    coords.iter().all(|&coord| {
+        let offset = to_f64(coord) - origin;
+        direction.x * offset.y - direction.y * offset.x == 0.0
+    })
+}
+
+/// Renders a rectangle as a `<rect>` element with [`Shapes::Primitives`], unless it is projected,
+/// has no area or has non-finite corners.
+fn rect_svg_str<T: CoordNum>(min: Coord<T>, max: Coord<T>, style: &Style) -> Option<String> {
+    if style.shapes != Some(Shapes::Primitives) || style.projection.is_some() {
+        return None;
+    }
+    if !is_finite(min) || !is_finite(max) || min.x == max.x || min.y == max.y {
+        return None;
     }
+    let (min, max) = match ClipRect::from_style(style) {
+        Some(clip_rect) => match clip_rect.clip_rect(min, max) {
+            Some(clipped) => clipped,
+            None => return Some(String::default()),
+        },
+        None => (min, max),
+    };
+    let corner_radius = style
+        .corner_radius
+        .map(|(rx, ry)| format!(r#" rx="{rx}" ry="{ry}""#))
+        .unwrap_or_default();
+    Some(format!(
+        r#"<rect x="{x:?}" y="{y:?}" width="{width:?}" height="{height:?}"{corner_radius}{style}/>"#,
+        x = min.x,
+        y = min.y,
+        width = max.x - min.x,
+        height = max.y - min.y,
+    ))
 }
 
 impl<T: ToSvgStr> ToSvgStr for &[T] {
@@ -247,6 +647,10 @@ impl<T: ToSvgStr> ToSvgStr for &[T] {
             view_box.add(&item.viewbox(style))
         })
     }
+
+    fn non_finite_coords(&self, style: &Style) -> usize {
+        self.iter().map(|item| item.non_finite_coords(style)).sum()
+    }
 }
 
 impl<T: ToSvgStr> ToSvgStr for Vec<T> {
@@ -261,12 +665,16 @@ impl<T: ToSvgStr> ToSvgStr for Vec<T> {
             view_box.add(&item.viewbox(style))
         })
     }
+
+    fn non_finite_coords(&self, style: &Style) -> usize {
+        self.iter().map(|item| item.non_finite_coords(style)).sum()
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{Color, ToSvg};
-    use geo_types::{LineString, Point, Polygon};
+    use crate::{Color, FillRule, PathEncoding, Projection, Shapes, ToSvg, ViewBox};
+    use geo_types::{Line, LineString, MultiPoint, MultiPolygon, Point, Polygon, Rect, Triangle};
 
     #[test]
     fn test_point() {
@@ -350,4 +758,270 @@ mod tests {
             r#"<svg xmlns="http://www.w3.org/2000/svg" preserveAspectRatio="xMidYMid meet" viewBox="209 -1 92 92"><path d="M 210.0 0.0 L 300.0 0.0 L 300.0 90.0 L 210.0 90.0" fill="black" stroke="red"/></svg>"#
         )
     }
+
+    #[test]
+    fn test_viewport_clips_line_string() {
+        let line_string =
+            LineString::from(vec![(0.0, 5.0), (100.0, 5.0), (100.0, 8.0), (0.0, 8.0)]);
+        let svg = line_string
+            .to_svg()
+            .with_stroke_width(1.0)
+            .with_viewport(ViewBox::new(0.0, 0.0, 10.0, 10.0));
+        assert_eq!(
+            svg.svg_str(),
+            r#"<path d="M 0.0 5.0 L 11.0 5.0 M 11.0 8.0 L 0.0 8.0" stroke-width="1"/>"#
+        );
+        assert_eq!(svg.viewbox(), ViewBox::new(0.0, 4.0, 10.0, 9.0));
+    }
+
+    #[test]
+    fn test_viewport_clips_polygon() {
+        let rect = Rect::new((-100.0, -100.0), (100.0, 100.0));
+        let svg = rect
+            .to_svg()
+            .with_stroke_width(1.0)
+            .with_viewport(ViewBox::new(0.0, 0.0, 10.0, 10.0));
+        assert_eq!(
+            svg.svg_str(),
+            r#"<path fill-rule="evenodd" d="M -1.0 11.0 L -1.0 -1.0 L 11.0 -1.0 L 11.0 11.0 L -1.0 11.0 Z " stroke-width="1"/>"#
+        );
+    }
+
+    #[test]
+    fn test_viewport_culls_outside_geometries() {
+        let points = MultiPoint::from(vec![(5.0, 5.0), (50.0, 50.0)]);
+        let rect = Rect::new((20.0, 20.0), (30.0, 30.0));
+        let svg = points
+            .to_svg()
+            .and(rect.to_svg())
+            .with_viewport(ViewBox::new(0.0, 0.0, 10.0, 10.0));
+        assert_eq!(svg.svg_str(), r#"<circle cx="5.0" cy="5.0" r="1"/>"#);
+
+        let unsigned = Point::new(0u32, 0u32);
+        let svg = unsigned
+            .to_svg()
+            .with_viewport(ViewBox::new(0.0, 0.0, 10.0, 10.0));
+        assert_eq!(svg.svg_str(), r#"<circle cx="0" cy="0" r="1"/>"#);
+    }
+
+    #[test]
+    fn test_projection() {
+        let line_string = LineString::from(vec![(0.0, 0.0), (10.0, 20.0)]);
+        let svg = line_string
+            .to_svg()
+            .with_stroke_width(0.0)
+            .with_projection(Projection::custom(|coord| coord * 2.0));
+        assert_eq!(
+            svg.svg_str(),
+            r#"<path d="M 0.0 0.0 L 20.0 40.0" stroke-width="0"/>"#
+        );
+        assert_eq!(svg.viewbox(), ViewBox::new(0.0, 0.0, 20.0, 40.0));
+    }
+
+    #[test]
+    fn test_compact_path_encoding() {
+        let polygon = Polygon::new(
+            LineString::from(vec![(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)]),
+            vec![LineString::from(vec![(2.0, 2.0), (8.0, 2.0), (5.0, 8.0)])],
+        );
+        let line = LineString::from(vec![(0.5, 0.5), (1.5, 1.5)]);
+        let svg = polygon
+            .to_svg()
+            .and(line.to_svg())
+            .with_path_encoding(PathEncoding::Compact);
+        assert_eq!(
+            svg.svg_str(),
+            r#"<path fill-rule="evenodd" d="M0 0h10v10H0zM2 2h6L5 8z"/><path d="M.5.5l1 1"/>"#
+        );
+    }
+
+    #[test]
+    fn test_degenerate_geometries() {
+        let point = Point::new(1.0, 2.0);
+        let point = point.to_svg().with_radius(3.0);
+        let one_point = LineString::from(vec![(1.0, 2.0)]);
+        let repeated = LineString::from(vec![(1.0, 2.0), (1.0, 2.0)]);
+        let zero_length = Line::new((1.0, 2.0), (1.0, 2.0));
+        for svg in [
+            one_point.to_svg().with_radius(3.0),
+            repeated.to_svg().with_radius(3.0),
+            zero_length.to_svg().with_radius(3.0),
+        ] {
+            assert_eq!(svg.svg_str(), point.svg_str());
+            assert_eq!(svg.viewbox(), point.viewbox());
+        }
+
+        let flat = Polygon::new(
+            LineString::from(vec![(0.0, 0.0), (2.0, 0.0), (4.0, 0.0)]),
+            vec![],
+        );
+        assert_eq!(
+            flat.to_svg().svg_str(),
+            r#"<path d="M 0.0 0.0 L 2.0 0.0 L 4.0 0.0"/>"#
+        );
+        assert_eq!(flat.to_svg().viewbox(), ViewBox::new(-1.0, -1.0, 5.0, 1.0));
+
+        // a zero signed area does not make a bowtie degenerate
+        let bowtie = Polygon::new(
+            LineString::from(vec![(0.0, 0.0), (2.0, 2.0), (2.0, 0.0), (0.0, 2.0)]),
+            vec![],
+        );
+        assert_eq!(
+            bowtie.to_svg().svg_str(),
+            r#"<path fill-rule="evenodd" d="M 0.0 0.0 L 2.0 2.0 L 2.0 0.0 L 0.0 2.0 L 0.0 0.0 Z "/>"#
+        );
+    }
+
+    #[test]
+    fn test_empty_geometries() {
+        let empty_polygon = Polygon::<f64>::new(LineString::new(vec![]), vec![]);
+        let empty_line_string = LineString::<f64>::new(vec![]);
+        let empty_multi_point = MultiPoint::<f64>::new(vec![]);
+        let svg = empty_polygon
+            .to_svg()
+            .and(empty_line_string.to_svg())
+            .and(empty_multi_point.to_svg());
+        assert_eq!(svg.svg_str(), "");
+        assert!(svg.viewbox().is_empty());
+
+        let holed = Polygon::new(
+            Rect::new((0.0, 0.0), (1.0, 1.0))
+                .to_polygon()
+                .exterior()
+                .clone(),
+            vec![LineString::new(vec![])],
+        );
+        assert_eq!(
+            holed.to_svg().svg_str(),
+            r#"<path fill-rule="evenodd" d="M 1.0 0.0 L 1.0 1.0 L 0.0 1.0 L 0.0 0.0 L 1.0 0.0 Z "/>"#
+        );
+    }
+
+    #[test]
+    fn test_primitives() {
+        let rect = Rect::new((0.0, 0.0), (4.0, 2.0));
+        assert_eq!(
+            rect.to_svg()
+                .with_shapes(Shapes::Primitives)
+                .with_corner_radius(0.5, 0.25)
+                .svg_str(),
+            r#"<rect x="0.0" y="0.0" width="4.0" height="2.0" rx="0.5" ry="0.25"/>"#
+        );
+        assert_eq!(
+            rect.to_svg()
+                .with_shapes(Shapes::Primitives)
+                .with_stroke_width(0.5)
+                .with_viewport(ViewBox::new(0.0, 0.0, 2.0, 2.0))
+                .svg_str(),
+            r#"<rect x="0.0" y="0.0" width="2.5" height="2.0" stroke-width="0.5"/>"#
+        );
+        assert_eq!(
+            Line::new((0.0, 0.0), (1.0, 2.0))
+                .to_svg()
+                .with_shapes(Shapes::Primitives)
+                .svg_str(),
+            r#"<line x1="0.0" y1="0.0" x2="1.0" y2="2.0"/>"#
+        );
+        assert_eq!(
+            Triangle::new((0.0, 0.0).into(), (2.0, 0.0).into(), (0.0, 2.0).into())
+                .to_svg()
+                .with_shapes(Shapes::Primitives)
+                .svg_str(),
+            r#"<polygon fill-rule="evenodd" points="0.0,0.0 2.0,0.0 0.0,2.0"/>"#
+        );
+        assert_eq!(
+            LineString::from(vec![(0.0, 0.0), (1.0, 1.0), (2.0, 0.0)])
+                .to_svg()
+                .with_shapes(Shapes::Primitives)
+                .svg_str(),
+            r#"<polyline points="0.0,0.0 1.0,1.0 2.0,0.0"/>"#
+        );
+
+        // holes need the even-odd fill rule of paths, and projected rectangles are polygons
+        let holed = Polygon::new(
+            rect.to_polygon().exterior().clone(),
+            vec![LineString::from(vec![(1.0, 0.5), (2.0, 0.5), (1.0, 1.5)])],
+        );
+        assert!(
+            holed
+                .to_svg()
+                .with_shapes(Shapes::Primitives)
+                .svg_str()
+                .starts_with("<path fill-rule=\"evenodd\"")
+        );
+        assert!(
+            rect.to_svg()
+                .with_shapes(Shapes::Primitives)
+                .with_projection(Projection::custom(|coord| coord * 2.0))
+                .svg_str()
+                .starts_with(r#"<polygon fill-rule="evenodd" points="#)
+        );
+    }
+
+    #[test]
+    fn test_fill_rule_and_single_path() {
+        let squares = MultiPolygon::new(vec![
+            Rect::new((0.0, 0.0), (2.0, 2.0)).to_polygon(),
+            Rect::new((1.0, 1.0), (3.0, 3.0)).to_polygon(),
+        ]);
+        assert_eq!(
+            squares
+                .to_svg()
+                .with_fill_rule(FillRule::NonZero)
+                .svg_str()
+                .matches(r#"<path fill-rule="nonzero""#)
+                .count(),
+            2
+        );
+        assert_eq!(
+            squares
+                .to_svg()
+                .with_fill_rule(FillRule::NonZero)
+                .with_single_path(true)
+                .with_path_encoding(PathEncoding::Compact)
+                .svg_str(),
+            r#"<path fill-rule="nonzero" d="M2 0v2H0V0zM3 1v2H1V1z"/>"#
+        );
+
+        // the fill rule of a self-overlapping ring holds for native polygons too
+        let pentagram = Polygon::new(
+            LineString::from(vec![
+                (0.0, 3.0),
+                (2.0, -3.0),
+                (-3.0, 1.0),
+                (3.0, 1.0),
+                (-2.0, -3.0),
+            ]),
+            vec![],
+        );
+        assert!(
+            pentagram
+                .to_svg()
+                .with_shapes(Shapes::Primitives)
+                .with_fill_rule(FillRule::NonZero)
+                .svg_str()
+                .starts_with(r#"<polygon fill-rule="nonzero" points="0.0,3.0 2.0,-3.0"#)
+        );
+
+        // overlaps are cut out with the even-odd rule
+        assert!(
+            squares
+                .to_svg()
+                .with_fill_rule(FillRule::EvenOdd)
+                .with_single_path(true)
+                .svg_str()
+                .starts_with(r#"<path fill-rule="evenodd" d="M 2.0 0.0"#)
+        );
+
+        // polygons without area are left out of the path
+        let flat = Polygon::new(LineString::from(vec![(0.0, 5.0), (4.0, 5.0)]), vec![]);
+        let with_flat = MultiPolygon::new(vec![squares.0[0].clone(), flat]);
+        assert_eq!(
+            with_flat.to_svg().with_single_path(true).svg_str(),
+            concat!(
+                r#"<path fill-rule="nonzero" d="M 2.0 0.0 L 2.0 2.0 L 0.0 2.0 L 0.0 0.0 L 2.0 0.0 Z "/>"#,
+                r#"<path d="M 0.0 5.0 L 4.0 5.0"/>"#
+            )
+        );
+    }
 }
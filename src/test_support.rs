@@ -0,0 +1,229 @@
+//! Snapshot testing of rendered geometries, enabled by the `test-support` feature.
+//!
+//! [`assert_svg_snapshot!`](crate::assert_svg_snapshot) renders an [`Svg`] pretty-printed and
+//! compares it to the golden file `tests/snapshots/<name>.svg` of the calling crate. On mismatch
+//! the new rendering is written next to it as `<name>.new.svg`, along with `<name>.diff.html`
+//! showing both pictures and the changed lines side by side, and the assertion fails.
+//!
+//! Set the environment variable `GEO_SVG_BLESS=1` to accept the new renderings as golden files.
+
+use crate::escape::escape;
+use crate::{PrettyPrint, Svg};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Environment variable which, when set to anything but `0`, overwrites the golden files.
+pub const BLESS_VAR: &str = "GEO_SVG_BLESS";
+
+/// Compares the rendering of an [`Svg`] to the golden file `tests/snapshots/<name>.svg`.
+///
+/// Example:
+/// ```no_run
+/// use geo_types::Point;
+/// use geo_svg::{ToSvg, assert_svg_snapshot};
+///
+/// let point = Point::new(10.0, 10.0);
+/// assert_svg_snapshot!("point", point.to_svg());
+/// ```
+#[macro_export]
+macro_rules! assert_svg_snapshot {
+    ($name:expr, $svg:expr $(,)?) => {
+        $crate::test_support::assert_snapshot(
+            ::std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("tests")
+                .join("snapshots"),
+            $name,
+            &$svg,
+        )
+    };
+}
+
+/// Function behind [`assert_svg_snapshot!`](crate::assert_svg_snapshot), looking for the golden
+/// file in `directory`.
+#[track_caller]
+pub fn assert_snapshot(directory: impl AsRef<Path>, name: &str, svg: &Svg) {
+    let bless = std::env::var(BLESS_VAR).is_ok_and(|bless| !bless.is_empty() && bless != "0");
+    if let Err(message) = check_snapshot(directory.as_ref(), name, &render(svg), bless) {
+        panic!("{message}");
+    }
+}
+
+/// Deterministic rendering of `svg`, one element per line so that golden files diff well.
+pub fn render(svg: &Svg) -> String {
+    let mut rendered = svg
+        .clone()
+        .with_pretty_print(PrettyPrint::default())
+        .to_string();
+    rendered.push('\n');
+    rendered
+}
+
+fn check_snapshot(directory: &Path, name: &str, rendered: &str, bless: bool) -> Result<(), String> {
+    let golden = directory.join(format!("{name}.svg"));
+    let new = directory.join(format!("{name}.new.svg"));
+    let diff = directory.join(format!("{name}.diff.html"));
+    let write = |path: &PathBuf, content: &str| {
+        fs::create_dir_all(directory)
+            .and_then(|_| fs::write(path, content))
+            .map_err(|error| format!("cannot write {}: {error}", path.display()))
+    };
+    let remove_outputs = || {
+        let _ = fs::remove_file(&new);
+        let _ = fs::remove_file(&diff);
+    };
+    if bless {
+        write(&golden, rendered)?;
+        remove_outputs();
+        return Ok(());
+    }
+    let Ok(expected) = fs::read_to_string(&golden) else {
+        write(&new, rendered)?;
+        return Err(format!(
+            "no snapshot {}, the rendering was written to {}; run with {BLESS_VAR}=1 to accept it",
+            golden.display(),
+            new.display(),
+        ));
+    };
+    let expected = expected.replace("\r\n", "\n");
+    if expected == rendered {
+        remove_outputs();
+        return Ok(());
+    }
+    write(&new, rendered)?;
+    write(&diff, &html_diff(name, &expected, rendered))?;
+    Err(format!(
+        "snapshot {} does not match, see {} and {}; run with {BLESS_VAR}=1 to accept the new rendering",
+        golden.display(),
+        new.display(),
+        diff.display(),
+    ))
+}
+
+#[derive(Debug, PartialEq)]
+enum Change<'s> {
+    Same(&'s str),
+    Removed(&'s str),
+    Added(&'s str),
+}
+
+/// Line diff of `expected` and `actual` following their longest common subsequence.
+fn diff_lines<'s>(expected: &'s str, actual: &'s str) -> Vec<Change<'s>> {
+    let expected: Vec<&str> = expected.lines().collect();
+    let actual: Vec<&str> = actual.lines().collect();
+    let mut common = vec![vec![0usize; actual.len() + 1]; expected.len() + 1];
+    for i in (0..expected.len()).rev() {
+        for j in (0..actual.len()).rev() {
+            common[i][j] = if expected[i] == actual[j] {
+                common[i + 1][j + 1] + 1
+            } else {
+                common[i + 1][j].max(common[i][j + 1])
+            };
+        }
+    }
+    let (mut i, mut j) = (0, 0);
+    let mut changes = vec![];
+    while i < expected.len() || j < actual.len() {
+        if i < expected.len() && j < actual.len() && expected[i] == actual[j] {
+            changes.push(Change::Same(expected[i]));
+            i += 1;
+            j += 1;
+        } else if j == actual.len() || i < expected.len() && common[i + 1][j] >= common[i][j + 1] {
+            changes.push(Change::Removed(expected[i]));
+            i += 1;
+        } else {
+            changes.push(Change::Added(actual[j]));
+            j += 1;
+        }
+    }
+    changes
+}
+
+/// Page showing the expected and actual pictures side by side, above the changed lines.
+fn html_diff(name: &str, expected: &str, actual: &str) -> String {
+    let mut rows = String::new();
+    for change in diff_lines(expected, actual) {
+        let (class, left, right) = match change {
+            Change::Same(line) => ("same", line, line),
+            Change::Removed(line) => ("removed", line, ""),
+            Change::Added(line) => ("added", "", line),
+        };
+        rows.push_str(&format!(
+            r#"<tr class="{class}"><td><pre>{}</pre></td><td><pre>{}</pre></td></tr>"#,
+            escape(left),
+            escape(right),
+        ));
+        rows.push('\n');
+    }
+    format!(
+        r#"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<title>{name}</title>
+<style>
+table {{ border-collapse: collapse; width: 100%; table-layout: fixed; }}
+td {{ vertical-align: top; border: 1px solid #ccc; }}
+td > svg {{ width: 100%; height: auto; max-height: 60vh; }}
+pre {{ margin: 0; white-space: pre-wrap; word-break: break-all; }}
+.removed td:first-child {{ background: #fdd; }}
+.added td:last-child {{ background: #dfd; }}
+</style>
+</head>
+<body>
+<h1>{name}</h1>
+<table>
+<tr><th>expected</th><th>actual</th></tr>
+<tr><td>{expected}</td><td>{actual}</td></tr>
+{rows}</table>
+</body>
+</html>
+"#,
+        name = escape(name),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ToSvg;
+    use geo_types::Point;
+
+    fn directory(test: &str) -> PathBuf {
+        let directory =
+            std::env::temp_dir().join(format!("geo-svg-snapshots-{test}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&directory);
+        directory
+    }
+
+    #[test]
+    fn test_diff_lines() {
+        assert_eq!(
+            diff_lines("a\nb\nc\n", "a\nx\nc\n"),
+            vec![
+                Change::Same("a"),
+                Change::Removed("b"),
+                Change::Added("x"),
+                Change::Same("c"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_snapshot() {
+        let directory = directory("snapshot");
+        let point = Point::new(1.0, 1.0);
+        let rendered = render(&point.to_svg());
+        assert!(check_snapshot(&directory, "point", &rendered, false).is_err());
+        assert!(directory.join("point.new.svg").exists());
+        assert!(check_snapshot(&directory, "point", &rendered, true).is_ok());
+        assert!(!directory.join("point.new.svg").exists());
+        assert!(check_snapshot(&directory, "point", &rendered, false).is_ok());
+
+        let moved = Point::new(2.0, 1.0);
+        let error = check_snapshot(&directory, "point", &render(&moved.to_svg()), false);
+        assert!(error.unwrap_err().contains("does not match"));
+        let diff = fs::read_to_string(directory.join("point.diff.html")).unwrap();
+        assert!(diff.contains(r#"<tr class="removed"><td><pre>  &lt;circle cx="1.0""#));
+        fs::remove_dir_all(&directory).unwrap();
+    }
+}
@@ -2,6 +2,9 @@ use std::fmt::Display;
 
 use geo_types::{Coord, CoordNum};
 
+use crate::clip::{ClipRect, Visibility};
+use crate::non_finite::{is_finite, non_finite};
+use crate::projection::project;
 use crate::{Style, ToSvgStr, ViewBox};
 
 // 🚧🚧 TODO 🚧🚧 : Implement this to find visual center of polygons to place text automatically
@@ -48,12 +51,20 @@ where
     S: Display,
     C: CoordNum + std::fmt::Display,
 {
-    fn to_svg_str(&self, _style: &Style) -> String {
+    fn to_svg_str(&self, style: &Style) -> String {
+        let position = project(style, self.position);
+        if !is_finite(position) {
+            return String::default();
+        }
+        if let Some(clip_rect) = ClipRect::from_style(style)
+            && clip_rect.visibility([position]) == Visibility::Outside
+        {
+            return String::default();
+        }
         let Text {
-            text,
-            position: Coord { x, y },
-            font_size,
+            text, font_size, ..
         } = self;
+        let Coord { x, y } = position;
         format!(r#"<text font-size="{font_size}" x="{x}" y="{y}">{text}</text>"#)
     }
 
@@ -69,4 +80,8 @@ where
             max_y: None,
         }
     }
+
+    fn non_finite_coords(&self, style: &Style) -> usize {
+        non_finite([self.position], style)
+    }
 }
@@ -0,0 +1,116 @@
+use crate::Style;
+use std::collections::BTreeMap;
+
+/// Styles shared across tools, by layer name, applied to the nodes of an [`Svg`](crate::Svg)
+/// tagged with [`Svg::with_layer`](crate::Svg::with_layer) when it is rendered with
+/// [`Svg::with_theme`](crate::Svg::with_theme).
+///
+/// The fields set in the style of a layer override those of the tagged nodes.
+///
+/// With the `toml` or `json` features, themes are loaded from files mapping layer names to
+/// styles:
+/// ```toml
+/// [roads]
+/// stroke-color = "#888888"
+/// stroke-width = 2
+///
+/// [debug-errors]
+/// fill = "red"
+/// fill-opacity = 0.5
+/// ```
+///
+/// Example:
+/// ```
+/// use geo_types::LineString;
+/// use geo_svg::{Color, Style, Theme, ToSvg};
+///
+/// let theme = Theme::default().with_layer(
+///     "roads",
+///     Style {
+///         stroke_color: Some(Color::GRAY),
+///         ..Style::default()
+///     },
+/// );
+/// let road = LineString::from(vec![(0.0, 0.0), (10.0, 5.0)]);
+/// let svg = road.to_svg().with_layer("roads").with_theme(&theme);
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct Theme {
+    layers: BTreeMap<String, Style<'static>>,
+}
+
+impl Theme {
+    /// Sets the style of the layer `name`.
+    pub fn with_layer(mut self, name: impl Into<String>, style: Style<'static>) -> Self {
+        self.layers.insert(name.into(), style);
+        self
+    }
+
+    /// The style of the layer `name`, if any.
+    pub fn layer(&self, name: &str) -> Option<&Style<'static>> {
+        self.layers.get(name)
+    }
+
+    /// Names of the layers styled by the theme, in alphabetical order.
+    pub fn layers(&self) -> impl Iterator<Item = &str> {
+        self.layers.keys().map(String::as_str)
+    }
+
+    /// Loads a theme from a TOML document with a table per layer.
+    #[cfg(feature = "toml")]
+    pub fn from_toml_str(toml: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(toml)
+    }
+
+    /// Loads a theme from a JSON object with an object per layer.
+    #[cfg(feature = "json")]
+    pub fn from_json_str(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "toml")]
+    #[test]
+    fn test_from_toml_str() {
+        use super::*;
+        use crate::Color;
+
+        let theme = Theme::from_toml_str(
+            r##"
+            [roads]
+            stroke-color = "#888888"
+            stroke-width = 2
+
+            [debug-errors]
+            fill = "red"
+            "##,
+        )
+        .unwrap();
+        assert_eq!(
+            theme.layers().collect::<Vec<_>>(),
+            ["debug-errors", "roads"]
+        );
+        assert_eq!(
+            theme.layer("roads").unwrap().stroke_color,
+            Some(Color::Hex(0x888888))
+        );
+        assert!(Theme::from_toml_str("[roads]\nfill = \"reddish\"").is_err());
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_from_json_str() {
+        use super::*;
+        use crate::Color;
+
+        let theme = Theme::from_json_str(r#"{"buildings": {"fill": "tan"}}"#).unwrap();
+        assert_eq!(
+            theme.layer("buildings").unwrap().fill,
+            Some(Color::TAN.into())
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{Style, Svg, ToSvgStr, ViewBox};
+use crate::{Svg, ToSvgStr};
 
 pub trait ToSvg {
     fn to_svg(&self) -> Svg<'_>;
@@ -6,13 +6,6 @@ pub trait ToSvg {
 
 impl<T: ToSvgStr> ToSvg for T {
     fn to_svg(&self) -> Svg<'_> {
-        Svg {
-            items: vec![self],
-            siblings: vec![],
-            viewbox: ViewBox::default(),
-            style: Style::default(),
-            width: None,
-            height: None,
-        }
+        Svg::new(vec![self], vec![])
     }
 }
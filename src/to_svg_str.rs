@@ -3,6 +3,17 @@ use crate::{Style, Svg, ViewBox};
 pub trait ToSvgStr {
     fn to_svg_str(&self, style: &Style) -> String;
     fn viewbox(&self, style: &Style) -> ViewBox;
+
+    /// Definitions referenced by the rendered item, to be written in `<defs>`.
+    fn defs(&self, style: &Style) -> Vec<String> {
+        style.defs()
+    }
+
+    /// Number of NaN or infinite coordinates once projected, which are left out of the
+    /// rendering, see [`Svg::non_finite_items`](crate::Svg::non_finite_items).
+    fn non_finite_coords(&self, _style: &Style) -> usize {
+        0
+    }
 }
 
 impl ToSvgStr for Svg<'_> {
@@ -13,4 +24,14 @@ impl ToSvgStr for Svg<'_> {
     fn viewbox(&self, style: &Style) -> ViewBox {
         self.clone().with_style(style).viewbox
     }
+
+    fn non_finite_coords(&self, style: &Style) -> usize {
+        let svg = self.clone().with_style(style);
+        svg.non_finite_items().iter().map(|item| item.coords).sum()
+    }
+
+    // a nested SVG writes its own definitions
+    fn defs(&self, _style: &Style) -> Vec<String> {
+        vec![]
+    }
 }
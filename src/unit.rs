@@ -52,6 +52,21 @@ impl Unit {
         }
     }
 
+    /// quantity converted to pixels, unitless values being taken as pixels
+    pub fn to_pixels(self) -> f32 {
+        let pixels_per_unit = match self {
+            Self::Centimeter(_) => 96.0 / 2.54,
+            Self::Inch(_) => 96.0,
+            Self::None(_) => 1.0,
+            Self::Millimeter(_) => 96.0 / 25.4,
+            Self::Pica(_) => 16.0,
+            Self::Pixel(_) => 1.0,
+            Self::Point(_) => 96.0 / 72.0,
+            Self::QuarterMillimeter(_) => 96.0 / 101.6,
+        };
+        self.value() * pixels_per_unit
+    }
+
     pub fn scale(self, factor: f32) -> Self {
         match self {
             Self::Centimeter(value) => Self::Centimeter(value * factor),
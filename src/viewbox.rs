@@ -1,4 +1,9 @@
+use geo_types::{Coord, CoordNum, Rect};
+use num_traits::NumCast;
+
 #[derive(Default, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct ViewBox {
     pub min_x: Option<f32>,
     pub min_y: Option<f32>,
@@ -25,6 +30,21 @@ impl ViewBox {
         }
     }
 
+    /// The area covered by both view boxes, empty if they do not overlap.
+    pub fn intersection(&self, other: &Self) -> Self {
+        let intersection = Self {
+            min_x: self.min_x.zip(other.min_x).map(|(a, b)| a.max(b)),
+            min_y: self.min_y.zip(other.min_y).map(|(a, b)| a.max(b)),
+            max_x: self.max_x.zip(other.max_x).map(|(a, b)| a.min(b)),
+            max_y: self.max_y.zip(other.max_y).map(|(a, b)| a.min(b)),
+        };
+        if intersection.is_empty() {
+            Self::default()
+        } else {
+            intersection
+        }
+    }
+
     pub fn min_x(&self) -> f32 {
         self.min_x.unwrap_or_default()
     }
@@ -74,4 +94,145 @@ impl ViewBox {
         self.max_y = self.max_y.map(|y| y + margin);
         self
     }
+
+    /// Grows the view box by a fraction of its size on each side, e.g. `0.1` for 10% of the
+    /// width on the left and right and 10% of the height on the top and bottom.
+    pub fn with_relative_margin(mut self, fraction: f32) -> Self {
+        let (margin_x, margin_y) = (self.width() * fraction, self.height() * fraction);
+        self.min_x = self.min_x.map(|x| x - margin_x);
+        self.min_y = self.min_y.map(|y| y - margin_y);
+        self.max_x = self.max_x.map(|x| x + margin_x);
+        self.max_y = self.max_y.map(|y| y + margin_y);
+        self
+    }
+
+    /// A view box is empty when it has no bounds, e.g. when nothing has been added to it or
+    /// when it results from the intersection of disjoint boxes.
+    pub fn is_empty(&self) -> bool {
+        match (self.min_x, self.min_y, self.max_x, self.max_y) {
+            (Some(min_x), Some(min_y), Some(max_x), Some(max_y)) => min_x > max_x || min_y > max_y,
+            _ => true,
+        }
+    }
+
+    /// Whether `other` lies entirely inside this view box.
+    pub fn contains(&self, other: &Self) -> bool {
+        !self.is_empty()
+            && !other.is_empty()
+            && self.min_x() <= other.min_x()
+            && self.min_y() <= other.min_y()
+            && self.max_x() >= other.max_x()
+            && self.max_y() >= other.max_y()
+    }
+
+    /// Whether `coord` lies inside this view box, boundary included.
+    pub fn contains_coord<T: CoordNum>(&self, coord: Coord<T>) -> bool {
+        let (Some(x), Some(y)) = (NumCast::from(coord.x), NumCast::from(coord.y)) else {
+            return false;
+        };
+        self.contains(&Self::new(x, y, x, y))
+    }
+
+    pub fn center(&self) -> Coord<f32> {
+        Coord {
+            x: (self.min_x() + self.max_x()) / 2.0,
+            y: (self.min_y() + self.max_y()) / 2.0,
+        }
+    }
+
+    /// Scales the view box by `factor` while keeping its center in place, `factor` greater than
+    /// one zooming out and smaller than one zooming in.
+    pub fn scale_about_center(&self, factor: f32) -> Self {
+        if self.is_empty() {
+            return *self;
+        }
+        let center = self.center();
+        let (half_width, half_height) = (self.width() * factor / 2.0, self.height() * factor / 2.0);
+        Self::new(
+            center.x - half_width,
+            center.y - half_height,
+            center.x + half_width,
+            center.y + half_height,
+        )
+    }
+
+    /// Grows the width or the height of the view box around its center so that the ratio of
+    /// its width to its height is `aspect_ratio`, e.g. to match the size of the output image.
+    pub fn expand_to_aspect_ratio(&self, aspect_ratio: f32) -> Self {
+        if self.is_empty() || aspect_ratio <= 0.0 {
+            return *self;
+        }
+        let center = self.center();
+        let width = self.width().max(self.height() * aspect_ratio);
+        let height = self.height().max(self.width() / aspect_ratio);
+        Self::new(
+            center.x - width / 2.0,
+            center.y - height / 2.0,
+            center.x + width / 2.0,
+            center.y + height / 2.0,
+        )
+    }
+
+    /// The view box as a rectangle, `None` if it is empty.
+    pub fn to_rect(&self) -> Option<Rect<f32>> {
+        (!self.is_empty())
+            .then(|| Rect::new((self.min_x(), self.min_y()), (self.max_x(), self.max_y())))
+    }
+}
+
+impl<T: CoordNum> From<Rect<T>> for ViewBox {
+    fn from(rect: Rect<T>) -> Self {
+        Self::new(
+            NumCast::from(rect.min().x).unwrap_or(0f32),
+            NumCast::from(rect.min().y).unwrap_or(0f32),
+            NumCast::from(rect.max().x).unwrap_or(0f32),
+            NumCast::from(rect.max().y).unwrap_or(0f32),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rect_conversion() {
+        let rect = Rect::new((0, 0), (10, 20));
+        let viewbox = ViewBox::from(rect);
+        assert_eq!(viewbox, ViewBox::new(0.0, 0.0, 10.0, 20.0));
+        assert_eq!(viewbox.to_rect(), Some(Rect::new((0.0, 0.0), (10.0, 20.0))));
+        assert_eq!(ViewBox::default().to_rect(), None);
+    }
+
+    #[test]
+    fn test_intersection_and_contains() {
+        let a = ViewBox::new(0.0, 0.0, 10.0, 10.0);
+        let b = ViewBox::new(5.0, 5.0, 20.0, 20.0);
+        assert_eq!(a.intersection(&b), ViewBox::new(5.0, 5.0, 10.0, 10.0));
+        assert!(a.contains(&a.intersection(&b)));
+        assert!(!a.contains(&b));
+        assert!(a.contains_coord(Coord { x: 10, y: 0 }));
+        assert!(
+            a.intersection(&ViewBox::new(11.0, 11.0, 12.0, 12.0))
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_camera_operations() {
+        let viewbox = ViewBox::new(0.0, 0.0, 10.0, 20.0);
+        assert_eq!(viewbox.center(), Coord { x: 5.0, y: 10.0 });
+        assert_eq!(
+            viewbox.scale_about_center(2.0),
+            ViewBox::new(-5.0, -10.0, 15.0, 30.0)
+        );
+        assert_eq!(
+            viewbox.expand_to_aspect_ratio(1.0),
+            ViewBox::new(-5.0, 0.0, 15.0, 20.0)
+        );
+        assert_eq!(
+            viewbox.with_relative_margin(0.1),
+            ViewBox::new(-1.0, -2.0, 11.0, 22.0)
+        );
+    }
 }
@@ -0,0 +1,128 @@
+//! Rendering of geometries in the Well-Known Binary format, in its ISO and PostGIS (EWKB)
+//! flavors, read with the [`wkb`] crate.
+
+use crate::{AnyGeometry, ParseGeometryError, Svg};
+use wkb::reader::read_wkb;
+
+impl<'a> Svg<'a> {
+    /// Parses a geometry in the Well-Known Binary format, e.g. from a database dump, in its ISO
+    /// or PostGIS (EWKB) flavor. The geometry is rendered straight from `wkb`, without copying
+    /// its coordinates.
+    ///
+    /// Example:
+    /// ```
+    /// use geo_svg::Svg;
+    ///
+    /// // POINT(1 2)
+    /// let wkb = [
+    ///     1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 240, 63, 0, 0, 0, 0, 0, 0, 0, 64,
+    /// ];
+    /// let svg = Svg::from_wkb_bytes(&wkb)?.with_radius(2.0);
+    /// assert!(Svg::from_wkb_bytes(&wkb[..10]).is_err());
+    /// # Ok::<(), geo_svg::ParseGeometryError>(())
+    /// ```
+    pub fn from_wkb_bytes(wkb: &'a [u8]) -> Result<Self, ParseGeometryError> {
+        let geometry = read_wkb(wkb).map_err(|error| ParseGeometryError::Wkb(error.to_string()))?;
+        Ok(Svg::from_owned(AnyGeometry(geometry)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ToSvg;
+    use geo_types::{Rect, line_string};
+
+    // flags of the geometry type in EWKB
+    const EWKB_Z: u32 = 0x8000_0000;
+    const EWKB_SRID: u32 = 0x2000_0000;
+
+    /// Encodes a header followed by `values`, `u32`s being counts and `f64`s coordinates.
+    fn wkb(little_endian: bool, code: u32, values: &[&dyn Value]) -> Vec<u8> {
+        let mut bytes = vec![u8::from(little_endian)];
+        code.write(&mut bytes, little_endian);
+        for value in values {
+            value.write(&mut bytes, little_endian);
+        }
+        bytes
+    }
+
+    trait Value {
+        fn write(&self, bytes: &mut Vec<u8>, little_endian: bool);
+    }
+
+    impl Value for u32 {
+        fn write(&self, bytes: &mut Vec<u8>, little_endian: bool) {
+            bytes.extend(if little_endian {
+                self.to_le_bytes()
+            } else {
+                self.to_be_bytes()
+            });
+        }
+    }
+
+    impl Value for f64 {
+        fn write(&self, bytes: &mut Vec<u8>, little_endian: bool) {
+            bytes.extend(if little_endian {
+                self.to_le_bytes()
+            } else {
+                self.to_be_bytes()
+            });
+        }
+    }
+
+    impl Value for Vec<u8> {
+        fn write(&self, bytes: &mut Vec<u8>, _: bool) {
+            bytes.extend(self);
+        }
+    }
+
+    fn svg_str(wkb: &[u8]) -> String {
+        Svg::from_wkb_bytes(wkb).unwrap().svg_str()
+    }
+
+    #[test]
+    fn test_point() {
+        let point = r#"<circle cx="1.0" cy="2.0" r="1"/>"#;
+        assert_eq!(svg_str(&wkb(true, 1, &[&1.0, &2.0])), point);
+        // ISO Z and EWKB Z with an SRID
+        assert_eq!(svg_str(&wkb(false, 1001, &[&1.0, &2.0, &3.0])), point);
+        let ewkb = wkb(true, 1 | EWKB_Z | EWKB_SRID, &[&4326u32, &1.0, &2.0, &3.0]);
+        assert_eq!(svg_str(&ewkb), point);
+        assert_eq!(svg_str(&wkb(true, 1, &[&f64::NAN, &f64::NAN])), "");
+    }
+
+    #[test]
+    fn test_polygon_and_collections() {
+        let square = Rect::new((0.0, 0.0), (1.0, 1.0)).to_polygon();
+        let mut values: Vec<&dyn Value> = vec![&1u32, &5u32];
+        let coords: Vec<f64> = square
+            .exterior()
+            .coords()
+            .flat_map(|c| [c.x, c.y])
+            .collect();
+        values.extend(coords.iter().map(|value| value as &dyn Value));
+        let polygon = wkb(false, 3, &values);
+        assert_eq!(svg_str(&polygon), square.to_svg().svg_str());
+
+        let line = wkb(true, 2, &[&2u32, &0.0, &0.0, &1.0, &1.0]);
+        let collection = wkb(true, 7, &[&2u32, &polygon, &line]);
+        let line_string = line_string![(x: 0.0, y: 0.0), (x: 1.0, y: 1.0)];
+        assert_eq!(
+            svg_str(&collection),
+            square.to_svg().and(line_string.to_svg()).svg_str()
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let line = wkb(true, 2, &[&2u32, &0.0, &0.0, &1.0, &1.0]);
+        assert!(matches!(
+            Svg::from_wkb_bytes(&line[..20]),
+            Err(ParseGeometryError::Wkb(_))
+        ));
+        assert!(Svg::from_wkb_bytes(&[2]).is_err());
+        let curve = wkb(true, 8, &[&0u32]);
+        assert!(Svg::from_wkb_bytes(&curve).is_err());
+    }
+}
@@ -0,0 +1,69 @@
+//! Rendering of geometries in the Well-Known Text format.
+
+use crate::{AnyGeometry, ParseGeometryError, Style, Svg, ToSvgStr, ViewBox};
+use geo_types::CoordNum;
+use wkt::Wkt;
+
+/// WKT values are rendered through their [`geo_traits`] implementation, without converting them
+/// to `geo_types`. Empty points are left out.
+impl<T: CoordNum> ToSvgStr for Wkt<T> {
+    fn to_svg_str(&self, style: &Style) -> String {
+        AnyGeometry(self).to_svg_str(style)
+    }
+
+    fn viewbox(&self, style: &Style) -> ViewBox {
+        AnyGeometry(self).viewbox(style)
+    }
+
+    fn non_finite_coords(&self, style: &Style) -> usize {
+        AnyGeometry(self).non_finite_coords(style)
+    }
+}
+
+impl Svg<'static> {
+    /// Parses a geometry in the Well-Known Text format, e.g. from a test fixture.
+    ///
+    /// Example:
+    /// ```
+    /// use geo_svg::Svg;
+    ///
+    /// let svg = Svg::from_wkt_str("POLYGON((0 0, 10 0, 10 10, 0 0))")?.with_stroke_width(0.5);
+    /// assert!(Svg::from_wkt_str("POLYGON((0 0, 10 0").is_err());
+    /// # Ok::<(), geo_svg::ParseGeometryError>(())
+    /// ```
+    pub fn from_wkt_str(wkt: &str) -> Result<Self, ParseGeometryError> {
+        let wkt: Wkt<f64> = wkt
+            .parse()
+            .map_err(|reason: &str| ParseGeometryError::Wkt(reason.to_string()))?;
+        Ok(Svg::from_owned(wkt))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ToSvg;
+
+    #[test]
+    fn test_wkt() {
+        let wkt: Wkt<f64> = "MULTIPOINT((0 0),(10 5))".parse().unwrap();
+        assert_eq!(
+            wkt.to_svg().svg_str(),
+            r#"<circle cx="0.0" cy="0.0" r="1"/><circle cx="10.0" cy="5.0" r="1"/>"#
+        );
+        let empty: Wkt<f64> = "POINT EMPTY".parse().unwrap();
+        assert_eq!(empty.to_svg().svg_str(), "");
+    }
+
+    #[test]
+    fn test_from_wkt_str() {
+        let svg = Svg::from_wkt_str("LINESTRING(0 0, 10 5)").unwrap();
+        assert_eq!(svg.svg_str(), r#"<path d="M 0.0 0.0 L 10.0 5.0"/>"#);
+        assert_eq!(svg.viewbox().max_x(), 11.0);
+        assert!(matches!(
+            Svg::from_wkt_str("LINESTRING(0 0, 10"),
+            Err(ParseGeometryError::Wkt(_))
+        ));
+        assert_eq!(Svg::from_wkt_str("POINT EMPTY").unwrap().svg_str(), "");
+    }
+}